@@ -0,0 +1,56 @@
+use aoc::{Answer, Solution};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2015;
+    const DAY: u32 = 1;
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.trim_end()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+fn part1(input: &str) -> i32 {
+    input
+        .chars()
+        .fold(0, |acc, c| if c == '(' { acc + 1 } else { acc - 1 })
+}
+
+fn part2(input: &str) -> usize {
+    input
+        .chars()
+        .scan(0, |acc, c| {
+            if c == '(' { *acc += 1 } else { *acc -= 1 };
+            Some(*acc)
+        })
+        .position(|floor| floor == -1)
+        .unwrap() + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::examples! {
+        Solver;
+        balanced: "(())" => part1 = 0;
+        alternating: "()()" => part1 = 0;
+        three: "(()(()(" => part1 = 3;
+        three_closing_first: "))(((((" => part1 = 3;
+        below: "())" => part1 = -1;
+        minus_three: ")())())" => part1 = -3;
+        basement_first: ")" => part2 = 1;
+        basement_fifth: "()())" => part2 = 5;
+    }
+}
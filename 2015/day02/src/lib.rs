@@ -0,0 +1,53 @@
+use aoc::{Answer, Solution};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2015;
+    const DAY: u32 = 2;
+
+    type Input<'a> = Vec<(u32, u32, u32)>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.lines().map(|l| {
+            let mut parts = l.split('x').map(|n| n.parse().unwrap());
+            (parts.next().unwrap(), parts.next().unwrap(), parts.next().unwrap())
+        })
+        .collect()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+fn part1(input: &[(u32, u32, u32)]) -> u32 {
+    input.iter().map(|&(l, w, h)| {
+        let (a, b, c) = (l * w, w * h, h * l);
+        2 * a + 2 * b + 2 * c + std::cmp::min(a, std::cmp::min(b, c))
+    }).sum()
+}
+
+fn part2(input: &[(u32, u32, u32)]) -> u32 {
+    input.iter().map(|&(l, w, h)| {
+        let a = 2 * l + 2 * w;
+        let b = 2 * w + 2 * h;
+        let c = 2 * h + 2 * l;
+        std::cmp::min(a, std::cmp::min(b, c)) + l * w * h
+    }).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::examples! {
+        Solver;
+        cube: "2x3x4" => part1 = 58, part2 = 34;
+        long: "1x1x10" => part1 = 43, part2 = 14;
+    }
+}
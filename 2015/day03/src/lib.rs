@@ -0,0 +1,71 @@
+use std::collections::HashSet;
+
+use aoc::{Answer, Solution};
+use geometry::{Dir4, Point2};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2015;
+    const DAY: u32 = 3;
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.trim_end()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+fn step(pos: &mut Point2, c: char) {
+    *pos += Dir4::from_char(c).unwrap().step();
+}
+
+fn part1(input: &str) -> usize {
+    let mut map = HashSet::new();
+    let mut pos = Point2::ORIGIN;
+    map.insert(pos);
+
+    for c in input.chars() {
+        step(&mut pos, c);
+        map.insert(pos);
+    }
+
+    map.len()
+}
+
+fn part2(input: &str) -> usize {
+    let mut map = HashSet::new();
+    let mut pos = [Point2::ORIGIN; 2];
+    map.insert(Point2::ORIGIN);
+    let mut who = 0;
+
+    for c in input.chars() {
+        step(&mut pos[who], c);
+        map.insert(pos[who]);
+
+        who = 1 - who;
+    }
+
+    map.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::examples! {
+        Solver;
+        east: ">" => part1 = 2;
+        square: "^>v<" => part1 = 4, part2 = 3;
+        up_down: "^v^v^v^v^v" => part1 = 2, part2 = 11;
+        robo_up_down: "^v" => part2 = 3;
+    }
+}
@@ -0,0 +1,90 @@
+use aoc::{Answer, Solution};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2015;
+    const DAY: u32 = 5;
+
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.lines().collect()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+fn is_vowel(c: u8) -> bool {
+    matches!(c, b'a' | b'e' | b'i' | b'o' | b'u')
+}
+
+fn is_nice_1(s: &str) -> bool {
+    let bytes = s.as_bytes().to_vec();
+    let mut vc = if is_vowel(bytes[0]) { 1 } else { 0 };
+    let mut twice = false;
+    for window in bytes.windows(2) {
+        if is_vowel(window[1]) {
+            vc += 1;
+        }
+        if window[0] == window[1] {
+            twice = true;
+        }
+        if window == b"ab" || window == b"cd" || window == b"pq" || window == b"xy" {
+            return false;
+        }
+    }
+
+    vc >= 3 && twice
+}
+
+fn is_nice_2(s: &str) -> bool {
+    let bytes = s.as_bytes().to_vec();
+    let mut pair = false;
+    let mut split_pair = false;
+
+    for (i, window) in bytes.windows(3).enumerate() {
+        for pair2 in bytes[i + 2..].windows(2) {
+            if window[0] == pair2[0] && window[1] == pair2[1] {
+                pair = true;
+            }
+        }
+        if window[0] == window[2] && window[0] != window[1] {
+            split_pair = true;
+        }
+    }
+
+    pair && split_pair
+}
+
+fn part1(input: &[&str]) -> usize {
+    input.iter().filter(|s| is_nice_1(s)).count()
+}
+
+fn part2(input: &[&str]) -> usize {
+    input.iter().filter(|s| is_nice_2(s)).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::examples! {
+        Solver;
+        nice: "ugknbfddgicrmopn
+aaa
+jchzalrnumimnmhp
+haegwjzuvuyypxyu
+dvszwmarrgswjxmb" => part1 = 2;
+        nicer: "qjhvhtzxzqqjkmpb
+xxyxx
+uurcxstgmygtbttf
+ieodomkazucvgmuy" => part2 = 2;
+    }
+}
@@ -0,0 +1,119 @@
+use aoc::{Answer, Solution};
+use grid::Grid;
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2015;
+    const DAY: u32 = 6;
+
+    type Input<'a> = Vec<Instruction>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        scan::or_panic(scan::lines(input, str::parse))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+enum Command {
+    On,
+    Toggle,
+    Off,
+}
+
+pub struct Instruction {
+    x0: u32,
+    y0: u32,
+    x1: u32,
+    y1: u32,
+    command: Command,
+}
+
+impl std::str::FromStr for Instruction {
+    type Err = scan::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Anything that is not toggled or turned off has to be turned on
+        let (command, pattern) = if s.starts_with("toggle") {
+            (Command::Toggle, "toggle {},{} through {},{}")
+        } else if s.starts_with("turn off") {
+            (Command::Off, "turn off {},{} through {},{}")
+        } else {
+            (Command::On, "turn on {},{} through {},{}")
+        };
+        let (x0, y0, x1, y1) = scan::scan!(s, pattern => u32, u32, u32, u32)?;
+
+        Ok(Self {
+            x0,
+            y0,
+            x1,
+            y1,
+            command,
+        })
+    }
+}
+
+const SIZE: usize = 1000;
+
+impl Instruction {
+    fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let (x0, x1) = (self.x0 as usize, self.x1 as usize);
+        (self.y0 as usize..=self.y1 as usize).flat_map(move |y| (x0..=x1).map(move |x| (x, y)))
+    }
+}
+
+fn part1(input: &[Instruction]) -> usize {
+    let mut grid = Grid::new(SIZE, SIZE, false);
+
+    for instr in input.iter() {
+        for pos in instr.positions() {
+            let light = &mut grid[pos];
+            match instr.command {
+                Command::On => *light = true,
+                Command::Toggle => *light = !*light,
+                Command::Off => *light = false,
+            }
+        }
+    }
+
+    grid.cells().iter().filter(|&&on| on).count()
+}
+
+fn part2(input: &[Instruction]) -> u32 {
+    let mut grid = Grid::new(SIZE, SIZE, 0u32);
+
+    for instr in input.iter() {
+        for pos in instr.positions() {
+            let light = &mut grid[pos];
+            match instr.command {
+                Command::On => *light += 1,
+                Command::Toggle => *light += 2,
+                Command::Off => *light = light.saturating_sub(1),
+            }
+        }
+    }
+
+    grid.cells().iter().sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::examples! {
+        Solver;
+        all_on: "turn on 0,0 through 999,999" => part1 = 1000000;
+        first_row: "toggle 0,0 through 999,0" => part1 = 1000;
+        middle_off: "turn on 0,0 through 999,999
+turn off 499,499 through 500,500" => part1 = 999996;
+        brightness_one: "turn on 0,0 through 0,0" => part2 = 1;
+        brightness_toggle: "toggle 0,0 through 999,999" => part2 = 2000000;
+    }
+}
@@ -0,0 +1,175 @@
+use std::collections::HashMap;
+
+use aoc::{Answer, Solution};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2015;
+    const DAY: u32 = 7;
+
+    type Input<'a> = Vec<Connection>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        scan::or_panic(scan::lines(input, str::parse))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Operand {
+    Value(u16),
+    Name(String),
+}
+
+impl Operand {
+    fn eval(&self, connections: &[Connection], wires: &mut HashMap<String, u16>) -> u16 {
+        match self {
+            Self::Value(n) => *n,
+            Self::Name(x) => signal(connections, find(connections, x), wires),
+        }
+    }
+}
+
+impl scan::FromField<'_> for Operand {
+    fn from_field(s: &str) -> Option<Self> {
+        Some(s.parse().map_or_else(|_| Operand::Name(s.to_owned()), Operand::Value))
+    }
+}
+
+enum Gate {
+    And,
+    Or,
+    LShift,
+    RShift,
+}
+
+impl scan::FromField<'_> for Gate {
+    fn from_field(s: &str) -> Option<Self> {
+        match s {
+            "AND" => Some(Gate::And),
+            "OR" => Some(Gate::Or),
+            "LSHIFT" => Some(Gate::LShift),
+            "RSHIFT" => Some(Gate::RShift),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Op {
+    Copy(Operand),
+    Not(String),
+    And(Operand, Operand),
+    Or(Operand, Operand),
+    LShift(Operand, Operand),
+    RShift(Operand, Operand),
+}
+
+#[derive(Debug, Clone)]
+pub struct Connection {
+    op: Op,
+    target: String,
+}
+
+impl std::str::FromStr for Connection {
+    type Err = scan::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // The gate is at the start of the line, so its errors have the right
+        // columns as they are
+        let (gate, target) = scan::scan!(s, "{} -> {}" => &str, String)?;
+        let op = match gate.matches(' ').count() {
+            0 => Op::Copy(scan::scan!(gate, "{}" => Operand)?.0),
+            1 => Op::Not(scan::scan!(gate, "NOT {}" => String)?.0),
+            _ => match scan::scan!(gate, "{} {} {}" => Operand, Gate, Operand)? {
+                (x, Gate::And, y) => Op::And(x, y),
+                (x, Gate::Or, y) => Op::Or(x, y),
+                (x, Gate::LShift, y) => Op::LShift(x, y),
+                (x, Gate::RShift, y) => Op::RShift(x, y),
+            },
+        };
+
+        Ok(Self { op, target })
+    }
+}
+
+fn find<'c>(connections: &'c [Connection], target: &str) -> &'c Connection {
+    connections.iter().find(|c| c.target == target).unwrap()
+}
+
+fn signal(connections: &[Connection], conn: &Connection, wires: &mut HashMap<String, u16>) -> u16 {
+    if let Some(&signal) = wires.get(&conn.target) {
+        return signal;
+    }
+
+    let signal = match conn.op {
+        Op::Copy(Operand::Value(n)) => n,
+        Op::Copy(Operand::Name(ref x)) => signal(connections, find(connections, x), wires),
+        Op::Not(ref x) => !signal(connections, find(connections, x), wires),
+        Op::And(ref x, ref y) => x.eval(connections, wires) & y.eval(connections, wires),
+        Op::Or(ref x, ref y) => x.eval(connections, wires) | y.eval(connections, wires),
+        Op::LShift(ref x, ref y) => x.eval(connections, wires) << y.eval(connections, wires),
+        Op::RShift(ref x, ref y) => x.eval(connections, wires) >> y.eval(connections, wires),
+    };
+
+    wires.insert(conn.target.clone(), signal);
+
+    signal
+}
+
+fn find_mut<'c>(connections: &'c mut [Connection], target: &str) -> &'c mut Connection {
+    connections.iter_mut().find(|c| c.target == target).unwrap()
+}
+
+fn part1(input: &[Connection]) -> u16 {
+    signal(input, find(input, "a"), &mut HashMap::new())
+}
+
+fn part2(input: &[Connection]) -> u16 {
+    let mut input = input.to_vec();
+    let a = signal(&input, find(&input, "a"), &mut HashMap::new());
+    let b = find_mut(&mut input, "b");
+    b.op = Op::Copy(Operand::Value(a));
+
+    signal(&input, find(&input, "a"), &mut HashMap::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let connections = Solver::parse("123 -> x
+456 -> y
+x AND y -> d
+x OR y -> e
+x LSHIFT 2 -> f
+y RSHIFT 2 -> g
+NOT x -> h
+NOT y -> i");
+
+        let mut wires = HashMap::new();
+        let expected = [
+            ("d", 72), ("e", 507), ("f", 492), ("g", 114),
+            ("h", 65412), ("i", 65079), ("x", 123), ("y", 456),
+        ];
+        for &(wire, value) in &expected {
+            assert_eq!(signal(&connections, find(&connections, wire), &mut wires), value, "wire {}", wire);
+        }
+    }
+
+    #[test]
+    fn test_unknown_gate() {
+        let error = "x XOR y -> z".parse::<Connection>().unwrap_err();
+        assert_eq!((error.line, error.column), (1, 3));
+    }
+}
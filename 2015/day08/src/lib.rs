@@ -0,0 +1,73 @@
+use aoc::{Answer, Solution};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2015;
+    const DAY: u32 = 8;
+
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.lines().collect()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+fn escape(s: &str) -> Vec<u8> {
+    let s = &s[1..s.len()-1];
+    let bs = s.as_bytes();
+    let mut e = Vec::new();
+    let mut i = 0;
+    while i < bs.len() {
+        let b0 = bs[i];
+        if b0 == b'\\' {
+            i += 1;
+            let b1 = bs[i];
+            if b1 == b'x' {
+                i += 1;
+                e.push(u8::from_str_radix(&s[i..i+2], 16).unwrap());
+                i += 2;
+            } else {
+                e.push(b1);
+                i += 1;
+            }
+        } else {
+            e.push(b0);
+            i += 1;
+        }
+    }
+    e
+}
+
+fn encode(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn part1(input: &[&str]) -> usize {
+    input.iter().map(|s| s.len() - escape(s).len()).sum()
+}
+
+fn part2(input: &[&str]) -> usize {
+    input.iter().map(|s| encode(s).len() - s.len()).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::examples! {
+        Solver;
+        strings: r#"""
+"abc"
+"aaa\"aaa"
+"\x27""# => part1 = 12, part2 = 19;
+    }
+}
@@ -0,0 +1,54 @@
+use aoc::{Answer, Solution};
+use search::tsp::{self, Distances, Objective, Route};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2015;
+    const DAY: u32 = 9;
+
+    type Input<'a> = Distances<u32>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+fn parse_input(input: &str) -> Distances<u32> {
+    let mut table = Distances::new();
+    for line in input.lines() {
+        let mut parts = line.split(' ');
+        let (from, _, to, _, dist) = (parts.next().unwrap(), parts.next(), parts.next().unwrap(), parts.next(), parts.next().unwrap().parse().unwrap());
+        let (from, to) = (table.node(from), table.node(to));
+        table.set_both(from, to, dist);
+    }
+    table
+}
+
+fn part1(table: &Distances<u32>) -> u32 {
+    tsp::solve(table, Objective::Min, Route::Path).unwrap().cost
+}
+
+fn part2(table: &Distances<u32>) -> u32 {
+    tsp::solve(table, Objective::Max, Route::Path).unwrap().cost
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::examples! {
+        Solver;
+        three_cities: "London to Dublin = 464
+London to Belfast = 518
+Dublin to Belfast = 141" => part1 = 605, part2 = 982;
+    }
+}
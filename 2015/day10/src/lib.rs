@@ -0,0 +1,70 @@
+use aoc::{Answer, Solution};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2015;
+    const DAY: u32 = 10;
+    const INPUT: Option<&'static str> = Some("1321131112");
+
+    type Input<'a> = Vec<u32>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.trim_end().bytes().map(|d| (d - b'0') as u32).collect()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input.clone()).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input.clone()).into()
+    }
+}
+
+fn look_and_say(input: &[u32]) -> Vec<u32> {
+    let mut seq = Vec::new();
+    let mut i = 0;
+    while i < input.len() {
+        let d = input[i];
+        let mut count = 1;
+        i += 1;
+        while i < input.len() && input[i] == d {
+            count += 1;
+            i += 1;
+        }
+        seq.push(count);
+        seq.push(d);
+    }
+    seq
+}
+
+fn part1(mut input: Vec<u32>) -> usize {
+    for _ in 0..40 {
+        input = look_and_say(&input);
+    }
+
+    input.len()
+}
+
+fn part2(mut input: Vec<u32>) -> usize {
+    for _ in 0..50 {
+        input = look_and_say(&input);
+    }
+
+    input.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_look_and_say() {
+        assert_eq!(look_and_say(&[1]), [1, 1]);
+        assert_eq!(look_and_say(&[1, 1]), [2, 1]);
+        assert_eq!(look_and_say(&[2, 1]), [1, 2, 1, 1]);
+        assert_eq!(look_and_say(&[1, 2, 1, 1]), [1, 1, 1, 2, 2, 1]);
+        assert_eq!(look_and_say(&[1, 1, 1, 2, 2, 1]), [3, 1, 2, 2, 1, 1]);
+    }
+}
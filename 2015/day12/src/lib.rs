@@ -0,0 +1,76 @@
+use aoc::{Answer, Solution};
+use serde_json::Value;
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2015;
+    const DAY: u32 = 12;
+
+    type Input<'a> = Value;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        serde_json::from_str(input).unwrap()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input.clone()).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input.clone()).into()
+    }
+}
+
+fn sum(val: Value) -> Option<i64> {
+    match val {
+        Value::Number(n) => n.as_i64(),
+        Value::Array(a) => Some(a.into_iter().filter_map(sum).sum()),
+        Value::Object(o) => Some(o.into_iter().filter_map(|(_, v)| sum(v)).sum()),
+        _ => None,
+    }
+}
+
+fn part1(val: Value) -> i64 {
+    sum(val).unwrap()
+}
+
+fn sum_without_red(val: Value) -> Option<i64> {
+    match val {
+        Value::Number(n) => n.as_i64(),
+        Value::Array(a) => Some(a.into_iter().filter_map(sum_without_red).sum()),
+        Value::Object(o) => {
+            if o.values().any(|v| v.is_string() && v.as_str().unwrap() == "red") {
+                None
+            } else {
+                Some(o.into_iter().filter_map(|(_, v)| sum_without_red(v)).sum())
+            }
+        }
+        _ => None,
+    }
+}
+
+fn part2(val: Value) -> i64 {
+    // the whole document is ignored if it is an object with a red value
+    sum_without_red(val).unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::examples! {
+        Solver;
+        array: "[1,2,3]" => part1 = 6, part2 = 6;
+        object: r#"{"a":2,"b":4}"# => part1 = 6;
+        nested_array: "[[[3]]]" => part1 = 3;
+        nested_object: r#"{"a":{"b":4},"c":-1}"# => part1 = 3;
+        negative_in_object: r#"{"a":[-1,1]}"# => part1 = 0;
+        negative_in_array: r#"[-1,{"a":1}]"# => part1 = 0;
+        empty_array: "[]" => part1 = 0;
+        empty_object: "{}" => part1 = 0;
+        red_object: r#"[1,{"c":"red","b":2},3]"# => part2 = 4;
+        red_root: r#"{"d":"red","e":[1,2,3,4],"f":5}"# => part2 = 0;
+        red_in_array: r#"[1,"red",5]"# => part2 = 6;
+    }
+}
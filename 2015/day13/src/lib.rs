@@ -0,0 +1,95 @@
+use aoc::{Answer, Solution};
+use search::tsp::{self, Distances, Objective, Route};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2015;
+    const DAY: u32 = 13;
+
+    type Input<'a> = Distances<i32>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input.clone()).into()
+    }
+}
+
+fn parse_rule(r: &str) -> Result<(&str, &str, i32), scan::Error> {
+    let (name, change, delta, other) = scan::scan!(
+        r,
+        "{} would {} {} happiness units by sitting next to {}." => &str, &str, i32, &str
+    )?;
+    let delta = if change == "gain" { delta } else { -delta };
+
+    Ok((name, other, delta))
+}
+
+/// The change in happiness of seating each pair of people next to each
+/// other, for both of them together.
+fn parse_input(input: &str) -> Distances<i32> {
+    let mut table = Distances::new();
+    for (name, other, delta) in scan::or_panic(scan::lines(input, parse_rule)) {
+        let (name, other) = (table.node(name), table.node(other));
+        let total = table.get(name, other).unwrap_or(0) + delta;
+        table.set_both(name, other, total);
+    }
+    table
+}
+
+fn arrange(table: &Distances<i32>) -> i32 {
+    tsp::solve(table, Objective::Max, Route::Cycle).unwrap().cost
+}
+
+fn part1(table: &Distances<i32>) -> i32 {
+    arrange(table)
+}
+
+fn part2(mut table: Distances<i32>) -> i32 {
+    let you = table.node("You");
+    for other in 0..you {
+        table.set_both(you, other, 0);
+    }
+    arrange(&table)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Alice would gain 54 happiness units by sitting next to Bob.
+Alice would lose 79 happiness units by sitting next to Carol.
+Alice would lose 2 happiness units by sitting next to David.
+Bob would gain 83 happiness units by sitting next to Alice.
+Bob would lose 7 happiness units by sitting next to Carol.
+Bob would lose 63 happiness units by sitting next to David.
+Carol would lose 62 happiness units by sitting next to Alice.
+Carol would gain 60 happiness units by sitting next to Bob.
+Carol would gain 55 happiness units by sitting next to David.
+David would gain 46 happiness units by sitting next to Alice.
+David would lose 7 happiness units by sitting next to Bob.
+David would gain 41 happiness units by sitting next to Carol.";
+
+    #[test]
+    fn test_arrange() {
+        let table = parse_input(EXAMPLE);
+        let seating = tsp::solve(&table, Objective::Max, Route::Cycle).unwrap();
+        let names: Vec<&str> = seating.order.iter().map(|&i| table.name(i)).collect();
+
+        assert_eq!(seating.cost, 330);
+        assert!(names == ["Alice", "Bob", "Carol", "David"] || names == ["Alice", "David", "Carol", "Bob"]);
+    }
+
+    aoc::examples! {
+        Solver;
+        four_people: EXAMPLE => part1 = 330;
+    }
+}
@@ -0,0 +1,101 @@
+use aoc::{Answer, Solution};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2015;
+    const DAY: u32 = 14;
+
+    type Input<'a> = Vec<Reindeer>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        scan::or_panic(scan::lines(input, str::parse))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input, TIME).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input, TIME).into()
+    }
+}
+
+pub struct Reindeer {
+    speed: u32,
+    time: u32,
+    rest: u32,
+}
+
+impl std::str::FromStr for Reindeer {
+    type Err = scan::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (_, speed, time, rest) = scan::scan!(
+            s,
+            "{} can fly {} km/s for {} seconds, but then must rest for {} seconds." => &str, u32, u32, u32
+        )?;
+
+        Ok(Self {
+            speed,
+            time,
+            rest,
+        })
+    }
+}
+
+const TIME: u32 = 2503;
+
+fn part1(reindeer: &[Reindeer], time: u32) -> u32 {
+    let mut max_dist = 0;
+    for r in reindeer {
+        let period = r.time + r.rest;
+        let first_dist = (time / period) * r.time * r.speed;
+        let time_left = time - (time / period) * period;
+        let dist = first_dist + u32::min(time_left, r.time) * r.speed;
+        if dist > max_dist {
+            max_dist = dist;
+        }
+    }
+    max_dist
+}
+
+fn part2(reindeer: &[Reindeer], time: u32) -> u32 {
+    let mut distances = vec![0; reindeer.len()];
+    let mut points = vec![0; reindeer.len()];
+    for t in 0..time {
+        for (i, r) in reindeer.iter().enumerate() {
+            let period = r.time + r.rest;
+            let start = (t / period) * period;
+            let end = (t / period) * period + r.time;
+            if t >= start && t < end {
+                distances[i] += r.speed;
+            }
+        }
+        let max = distances.iter().max().unwrap();
+        for (d, p) in distances.iter().zip(points.iter_mut()) {
+            if d == max {
+                *p += 1;
+            }
+        }
+    }
+
+    *points.iter().max().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds.
+Dancer can fly 16 km/s for 11 seconds, but then must rest for 162 seconds.";
+
+    #[test]
+    fn test_example() {
+        let reindeer = Solver::parse(EXAMPLE);
+
+        assert_eq!(part1(&reindeer, 1000), 1120);
+        assert_eq!(part2(&reindeer, 1000), 689);
+    }
+}
@@ -0,0 +1,83 @@
+use aoc::{Answer, Solution};
+use combinatorics::compositions;
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2015;
+    const DAY: u32 = 15;
+
+    type Input<'a> = Vec<Ingredient>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+type Ingredient = [i64; 5];
+
+fn parse_ingredient(s: &str) -> Result<Ingredient, scan::Error> {
+    let (_, capacity, durability, flavor, texture, calories) = scan::scan!(
+        s,
+        "{}: capacity {}, durability {}, flavor {}, texture {}, calories {}" => &str, i64, i64, i64, i64, i64
+    )?;
+
+    Ok([capacity, durability, flavor, texture, calories])
+}
+
+fn parse_input(s: &str) -> Vec<Ingredient> {
+    scan::or_panic(scan::lines(s, parse_ingredient))
+}
+
+const TEASPOONS: u32 = 100;
+
+/// Every way to divide the teaspoons over `n` ingredients, with at least
+/// one of each.
+fn recipes(n: usize) -> impl Iterator<Item = Vec<u32>> {
+    compositions(TEASPOONS - n as u32, n).map(|extra| extra.iter().map(|&amount| amount + 1).collect())
+}
+
+/// The score of a recipe and its number of calories.
+fn score(ingredients: &[Ingredient], amounts: &[u32]) -> (i64, i64) {
+    let property = |i: usize| -> i64 {
+        ingredients.iter().zip(amounts).map(|(ingredient, &n)| ingredient[i] * n as i64).sum()
+    };
+
+    let score = (0..4).map(|i| property(i).max(0)).product();
+    (score, property(4))
+}
+
+fn part1(ingredients: &[Ingredient]) -> i64 {
+    recipes(ingredients.len())
+        .map(|amounts| score(ingredients, &amounts).0)
+        .max()
+        .unwrap()
+}
+
+fn part2(ingredients: &[Ingredient]) -> i64 {
+    recipes(ingredients.len())
+        .map(|amounts| score(ingredients, &amounts))
+        .filter(|&(_, calories)| calories == 500)
+        .map(|(score, _)| score)
+        .max()
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::examples! {
+        Solver;
+        two_ingredients: "Butterscotch: capacity -1, durability -2, flavor 6, texture 3, calories 8
+Cinnamon: capacity 2, durability 3, flavor -2, texture -1, calories 3" => part1 = 62842880, part2 = 57600000;
+    }
+}
@@ -0,0 +1,62 @@
+use aoc::{Answer, Solution};
+use combinatorics::subsets;
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2015;
+    const DAY: u32 = 17;
+
+    type Input<'a> = Vec<u32>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.lines().map(|l| l.parse().unwrap()).collect()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+/// The number of containers in each combination that holds exactly `total`.
+fn fits(containers: &[u32], total: u32) -> impl Iterator<Item = usize> {
+    subsets(containers)
+        .filter(move |combo| combo.iter().sum::<u32>() == total)
+        .map(|combo| combo.len())
+}
+
+fn combinations(containers: &[u32], total: u32, containers_used: Option<usize>) -> usize {
+    fits(containers, total)
+        .filter(|&used| containers_used.is_none_or(|u| used == u))
+        .count()
+}
+
+fn part1(containers: &[u32]) -> usize {
+    combinations(containers, 150, None)
+}
+
+fn min_combinations(containers: &[u32], total: u32) -> usize {
+    let min_used = fits(containers, total).min();
+    min_used.map_or(0, |min_used| combinations(containers, total, Some(min_used)))
+}
+
+fn part2(containers: &[u32]) -> usize {
+    min_combinations(containers, 150)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let containers = [20, 15, 10, 5, 5];
+
+        assert_eq!(combinations(&containers, 25, None), 4);
+        assert_eq!(min_combinations(&containers, 25), 3);
+    }
+}
@@ -0,0 +1,73 @@
+use aoc::{Answer, Solution};
+use automaton::{Automaton, Boundary, Neighborhood};
+use grid::Grid;
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2015;
+    const DAY: u32 = 18;
+
+    type Input<'a> = Grid<bool>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        Grid::parse(input, |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })
+        .unwrap()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input, STEPS).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input, STEPS).into()
+    }
+}
+
+const STEPS: usize = 100;
+
+fn conway(on: bool, neighbors: &[bool]) -> bool {
+    let count = neighbors.iter().filter(|&&on| on).count();
+    count == 3 || (on && count == 2)
+}
+
+fn part1(grid: &Grid<bool>, steps: usize) -> usize {
+    let mut lights = Automaton::new(grid.clone(), Neighborhood::Moore, Boundary::Fixed);
+    lights.run(steps, conway);
+    lights.count(|&on| on)
+}
+
+fn part2(grid: &Grid<bool>, steps: usize) -> usize {
+    let (w, h) = (grid.width(), grid.height());
+    let mut lights = Automaton::new(grid.clone(), Neighborhood::Moore, Boundary::Fixed);
+    for corner in [(0, 0), (w - 1, 0), (0, h - 1), (w - 1, h - 1)] {
+        lights.pin(corner, true);
+    }
+    lights.run(steps, conway);
+    lights.count(|&on| on)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+.#.#.#
+...##.
+#....#
+..#...
+#.#..#
+####..";
+
+    #[test]
+    fn test_example() {
+        let grid = Solver::parse(EXAMPLE);
+
+        assert_eq!(part1(&grid, 4), 4);
+        assert_eq!(part2(&grid, 5), 17);
+    }
+}
@@ -0,0 +1,72 @@
+extern crate aoc;
+extern crate cycle;
+
+use aoc::{Answer, Solution};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2018;
+    const DAY: u32 = 1;
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+fn part1(input: &str) -> i32 {
+    input.lines()
+        .fold(0, |a, l| a + l.parse::<i32>().unwrap())
+}
+
+fn part2(input: &str) -> i32 {
+    let changes: Vec<i32> = input.lines()
+        .map(|l| l.parse().unwrap())
+        .collect();
+    let step = |&(i, freq): &(usize, i32)| ((i + 1) % changes.len(), freq + changes[i]);
+    let (_, (_, freq)) = cycle::find_by_key((0, 0), step, |&(_, freq)| freq);
+    freq
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::examples! {
+        Solver;
+        mixed: "+1
+-2
++3
++1" => part1 = 3, part2 = 2;
+        increasing: "+1
++1
++1" => part1 = 3;
+        zero: "+1
+-1" => part2 = 0;
+        ten: "+3
++3
++4
+-2
+-4" => part2 = 10;
+        five: "-6
++3
++8
++5
+-6" => part2 = 5;
+        fourteen: "+7
++7
+-2
+-7
+-4" => part2 = 14;
+    }
+}
@@ -0,0 +1,80 @@
+extern crate aoc;
+
+use std::collections::HashMap;
+
+use aoc::{Answer, Solution};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2018;
+    const DAY: u32 = 2;
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+fn part1(input: &str) -> usize {
+    let mut count2 = 0;
+    let mut count3 = 0;
+    let mut chars: HashMap<char, usize> = HashMap::new();
+    for line in input.lines() {
+        for c in line.chars() {
+            *chars.entry(c).or_insert(0) += 1;
+        }
+        if chars.values().any(|&c| c == 2) { count2 += 1; }
+        if chars.values().any(|&c| c == 3) { count3 += 1; }
+        chars.clear();
+    }
+    count2*count3
+}
+
+fn part2(input: &str) -> String {
+    for first in input.lines() {
+        for second in input.lines() {
+            let common: String = first.chars()
+                .zip(second.chars())
+                .filter(|(a, b)| a == b)
+                .map(|(a, _)| a)
+                .collect();
+            if common.len() == first.len() - 1 {
+                return common;
+            }
+        }
+    }
+    unreachable!()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::examples! {
+        Solver;
+        checksum: "abcdef
+bababc
+abbcde
+abcccd
+aabcdd
+abcdee
+ababab" => part1 = 12;
+        common_letters: "abcde
+fghij
+klmno
+pqrst
+fguij
+axcye
+wvxyz" => part2 = "fgij";
+    }
+}
@@ -0,0 +1,96 @@
+extern crate aoc;
+extern crate grid;
+
+use aoc::{Answer, Solution};
+use grid::Grid;
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2018;
+    const DAY: u32 = 3;
+
+    type Input<'a> = Vec<Claim>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.lines().map(parse_claim).collect()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+pub struct Claim {
+    n: usize,
+    x: usize,
+    y: usize,
+    w: usize,
+    h: usize,
+}
+
+fn parse_claim(line: &str) -> Claim {
+    let nums: Vec<_> = line.trim()
+        .split(|c: char| !c.is_ascii_digit())
+        .filter_map(|part| part.parse().ok())
+        .collect();
+
+    Claim {
+        n: nums[0],
+        x: nums[1],
+        y: nums[2],
+        w: nums[3],
+        h: nums[4],
+    }
+}
+
+const SIZE: usize = 1000;
+
+impl Claim {
+    fn squares(&self) -> impl Iterator<Item = (usize, usize)> {
+        let (x, w) = (self.x, self.w);
+        (self.y..self.y + self.h).flat_map(move |y| (x..x + w).map(move |x| (x, y)))
+    }
+}
+
+/// The number of claims on each square inch of the fabric.
+fn claim_counts(input: &[Claim]) -> Grid<usize> {
+    let mut fabric = Grid::new(SIZE, SIZE, 0);
+    for claim in input {
+        for square in claim.squares() {
+            fabric[square] += 1;
+        }
+    }
+    fabric
+}
+
+fn part1(input: &[Claim]) -> usize {
+    claim_counts(input).cells().iter()
+        .filter(|&&n| n >= 2)
+        .count()
+}
+
+fn part2(input: &[Claim]) -> usize {
+    let fabric = claim_counts(input);
+
+    input.iter()
+        .find(|claim| claim.squares().all(|square| fabric[square] == 1))
+        .unwrap()
+        .n
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::examples! {
+        Solver;
+        three_claims: "#1 @ 1,3: 4x4
+#2 @ 3,1: 4x4
+#3 @ 5,5: 2x2" => part1 = 4, part2 = 3;
+    }
+}
@@ -0,0 +1,57 @@
+use aoc::{Answer, Solution};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2019;
+    const DAY: u32 = 1;
+
+    type Input<'a> = Vec<i32>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.lines().map(|n| n.parse().unwrap()).collect()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+fn part1(input: &[i32]) -> i32 {
+    input
+        .iter()
+        .map(|mass| mass / 3 - 2)
+        .sum()
+}
+
+fn part2(input: &[i32]) -> i32 {
+    input
+        .iter()
+        .map(|mass| {
+            let mut next = mass / 3 - 2;
+            let mut total = 0;
+            while next > 0 {
+                total += next;
+                next = next / 3 - 2;
+            }
+            total
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::examples! {
+        Solver;
+        twelve: "12" => part1 = 2;
+        fourteen: "14" => part1 = 2, part2 = 2;
+        medium: "1969" => part1 = 654, part2 = 966;
+        large: "100756" => part1 = 33583, part2 = 50346;
+    }
+}
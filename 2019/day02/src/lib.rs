@@ -0,0 +1,49 @@
+use aoc::{Answer, Solution};
+use intcode::Computer;
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2019;
+    const DAY: u32 = 2;
+
+    type Input<'a> = Computer;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.parse().unwrap()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input.clone()).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input.clone()).into()
+    }
+}
+
+fn part1(mut cpu: Computer) -> i64 {
+    cpu.memory[1] = 12;
+    cpu.memory[2] = 2;
+
+    let _ = cpu.run(&[]);
+
+    cpu.memory[0]
+}
+
+fn part2(cpu: Computer) -> i64 {
+    const VALUE: i64 = 19690720;
+
+    for noun in 0..99 {
+        for verb in 0..99 {
+            let mut cpu = cpu.clone();
+            cpu.memory[1] = noun;
+            cpu.memory[2] = verb;
+            let _ = cpu.run(&[]);
+            if cpu.memory[0] == VALUE {
+                return 100 * noun + verb;
+            }
+        }
+    }
+    unreachable!()
+}
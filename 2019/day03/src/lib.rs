@@ -0,0 +1,150 @@
+use aoc::{Answer, Solution};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2019;
+    const DAY: u32 = 3;
+
+    type Input<'a> = Vec<Vec<Segment>>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.lines().map(parse_wire).collect()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(&input[0], &input[1]).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(&input[0], &input[1]).into()
+    }
+}
+
+pub struct Segment {
+    x1: i32,
+    y1: i32,
+    x2: i32,
+    y2: i32,
+    steps: i32,
+}
+
+struct Intersection {
+    x: i32,
+    y: i32,
+    steps: i32,
+}
+
+impl Segment {
+    /// Normalize a segment so it always points right or down.
+    fn normalize(&self) -> Segment {
+        Segment {
+            x1: i32::min(self.x1, self.x2),
+            y1: i32::min(self.y1, self.y2),
+            x2: i32::max(self.x1, self.x2),
+            y2: i32::max(self.y1, self.y2),
+            steps: -1,
+        }
+    }
+
+    /// Find the intersection point with another segment if it exists.
+    /// Also returns the number of combined steps from the starting point.
+    fn intersect(&self, other: &Segment) -> Option<Intersection> {
+        // Central point is not an intersection
+        if self.x1 == 0 && self.y1 == 0 && other.x1 == 0 && other.y1 == 0 {
+            return None;
+        }
+
+        // Self vertical, other horizontal
+        if self.x1 == self.x2 && other.y1 == other.y2 {
+            let self_norm = self.normalize();
+            let other_norm = other.normalize();
+            if other_norm.x1 <= self_norm.x1 && other_norm.x2 >= self_norm.x2
+                && self_norm.y1 <= other_norm.y1 && self_norm.y2 >= other_norm.y2
+            {
+                let x = self_norm.x1;
+                let y = other_norm.y1;
+                let steps = self.steps + other.steps - (y - self.y2).abs() - (x - other.x2).abs();
+
+                return Some(Intersection { x, y, steps, });
+            }
+        // Self horizontal, other vertical
+        } else if self.y1 == self.y2 && other.x1 == other.x2 {
+            return other.intersect(self);
+        }
+
+        None
+    }
+}
+
+fn parse_wire(line: &str) -> Vec<Segment> {
+    let mut wire = Vec::new();
+
+    line.split(',')
+        .fold((0, 0, 0), |(x, y, steps), movement| {
+            let n: i32 = movement[1..].parse().unwrap();
+            let next = match &movement[..1] {
+                "U" => (x, y + n),
+                "D" => (x, y - n),
+                "L" => (x - n, y),
+                "R" => (x + n, y),
+                _ => unreachable!(),
+            };
+            wire.push(Segment {
+                x1: x,
+                y1: y,
+                x2: next.0,
+                y2: next.1,
+                steps: steps + n,
+            });
+            (next.0, next.1, steps + n)
+        });
+
+    wire
+}
+
+/// Part 1: find the intersection closest to the central point.
+fn part1(wire_1: &[Segment], wire_2: &[Segment]) -> i32 {
+    let mut min_dist = i32::MAX;
+    for segment_1 in wire_1 {
+        for segment_2 in wire_2 {
+            if let Some(Intersection { x, y, steps: _ }) = segment_1.intersect(segment_2) {
+                let dist = x.abs() + y.abs();
+                if dist < min_dist {
+                    min_dist = dist;
+                }
+            }
+        }
+    }
+    min_dist
+}
+
+/// Part 2: find the intersection with the least amount of combined steps from the central point.
+fn part2(wire_1: &[Segment], wire_2: &[Segment]) -> i32 {
+    let mut min_steps = i32::MAX;
+    for segment_1 in wire_1 {
+        for segment_2 in wire_2 {
+            if let Some(intersection) = segment_1.intersect(segment_2) {
+                if intersection.steps < min_steps {
+                    min_steps = intersection.steps;
+                }
+            }
+        }
+    }
+    min_steps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::examples! {
+        Solver;
+        small: "R8,U5,L5,D3
+U7,R6,D4,L4" => part1 = 6, part2 = 30;
+        medium: "R75,D30,R83,U83,L12,D49,R71,U7,L72
+U62,R66,U55,R34,D71,R55,D58,R83" => part1 = 159, part2 = 610;
+        large: "R98,U47,R26,D63,R33,U87,L62,D20,R33,U53,R51
+U98,R91,D20,R16,D67,R40,U7,R15,U6,R7" => part1 = 135, part2 = 410;
+    }
+}
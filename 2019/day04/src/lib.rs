@@ -0,0 +1,92 @@
+use aoc::{Answer, Solution};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2019;
+    const DAY: u32 = 4;
+
+    type Input<'a> = (u32, u32);
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        let a = input[..6].parse().unwrap();
+        let b = input[7..].trim_end().parse().unwrap();
+        (a, b)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input.0, input.1).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input.0, input.1).into()
+    }
+}
+
+fn is_valid(mut n: u32) -> bool {
+    let mut double = false;
+    while n > 0 {
+        let d = n % 10;
+        if n >= 10 {
+            let next = (n / 10) % 10;
+            if next == d {
+                double = true;
+            }
+            if next > d {
+                return false;
+            }
+        }
+        n /= 10;
+    }
+    double
+}
+
+fn is_valid_ext(mut n: u32) -> bool {
+    let mut double = false;
+    let mut d = n % 10;
+    let mut group_count = 1;
+    while n > 0 {
+        n /= 10;
+        let next = n % 10;
+        if next > d {
+            return false;
+        }
+        if next != d {
+            if group_count == 2 {
+                double = true;
+            }
+            group_count = 1;
+        } else {
+            group_count += 1;
+        }
+        d = next;
+    }
+    double
+}
+
+fn part1(a: u32, b: u32) -> usize {
+    (a..=b).filter(|&n| is_valid(n)).count()
+}
+
+fn part2(a: u32, b: u32) -> usize {
+    (a..=b).filter(|&n| is_valid_ext(n)).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_valid() {
+        assert!(is_valid(111111));
+        assert!(!is_valid(223450));
+        assert!(!is_valid(123789));
+    }
+
+    #[test]
+    fn test_is_valid_ext() {
+        assert!(is_valid_ext(112233));
+        assert!(!is_valid_ext(123444));
+        assert!(is_valid_ext(111122));
+    }
+}
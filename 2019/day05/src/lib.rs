@@ -0,0 +1,50 @@
+use aoc::{Answer, Solution};
+use intcode::Computer;
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2019;
+    const DAY: u32 = 5;
+
+    type Input<'a> = Computer;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.parse().unwrap()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input.clone()).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input.clone()).into()
+    }
+}
+
+fn part1(mut cpu: Computer) -> i64 {
+    let outputs = cpu.run(&[1]);
+
+    outputs[outputs.len() - 1]
+}
+
+fn part2(mut cpu: Computer) -> i64 {
+    let outputs = cpu.run(&[5]);
+
+    outputs[0]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::examples! {
+        Solver;
+        echo: "3,0,4,0,99" => part1 = 1, part2 = 5;
+        less_than_8: "3,3,1107,-1,8,3,4,3,99" => part1 = 1, part2 = 1;
+        equal_to_8: "3,9,8,9,10,9,4,9,99,-1,8" => part1 = 0, part2 = 0;
+        compare_to_8: "3,21,1008,21,8,20,1005,20,22,107,8,21,20,1006,20,31,\
+1106,0,36,98,0,0,1002,21,125,20,4,20,1105,1,46,104,\
+999,1105,1,46,1101,1000,1,20,4,20,1105,1,46,98,99" => part1 = 999, part2 = 999;
+    }
+}
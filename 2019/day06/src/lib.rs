@@ -0,0 +1,94 @@
+use std::collections::HashMap;
+
+use aoc::{Answer, Solution};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2019;
+    const DAY: u32 = 6;
+
+    type Input<'a> = HashMap<String, Object>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse_orbits(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[derive(Debug)]
+pub struct Object {
+    parent: Option<String>,
+}
+
+fn parse_orbits(input: &str) -> HashMap<String, Object> {
+    let mut objects = HashMap::new();
+    for rel in input.lines() {
+        let mut pair = rel.split(')');
+        let name_a = pair.next().unwrap().to_owned();
+        let name_b = pair.next().unwrap().to_owned();
+
+        objects.entry(name_a.clone()).or_insert(Object { parent: None });
+
+        let b = objects.entry(name_b).or_insert(Object { parent: None });
+        b.parent.replace(name_a);
+    }
+    objects
+}
+
+/// Which objects orbit or are orbited by each object.
+fn neighbors(objects: &HashMap<String, Object>) -> HashMap<&str, Vec<&str>> {
+    let mut neighbors: HashMap<&str, Vec<&str>> = HashMap::new();
+    for (name, object) in objects {
+        if let Some(parent) = &object.parent {
+            neighbors.entry(name).or_default().push(parent);
+            neighbors.entry(parent).or_default().push(name);
+        }
+    }
+    neighbors
+}
+
+fn part1(objects: &HashMap<String, Object>) -> usize {
+    let neighbors = neighbors(objects);
+    search::bfs_all("COM", |name| neighbors[name].iter().copied())
+        .costs()
+        .map(|(_, depth)| depth)
+        .sum()
+}
+
+fn part2(objects: &HashMap<String, Object>) -> usize {
+    let neighbors = neighbors(objects);
+    let you = objects["YOU"].parent.as_deref().unwrap();
+    let santa = objects["SAN"].parent.as_deref().unwrap();
+    search::bidirectional_bfs(you, santa, |name| neighbors[name].iter().copied())
+        .unwrap()
+        .cost
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::examples! {
+        Solver;
+        orbits: "COM)B
+B)C
+C)D
+D)E
+E)F
+B)G
+G)H
+D)I
+E)J
+J)K
+K)L" => part1 = 42;
+        transfers: include_str!("../example") => part1 = 54, part2 = 4;
+    }
+}
@@ -0,0 +1,100 @@
+use aoc::{Answer, Solution};
+use combinatorics::permutations;
+use intcode::{Computer, InterpretStep};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2019;
+    const DAY: u32 = 7;
+
+    type Input<'a> = Computer;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.parse().unwrap()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input.clone()).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input.clone()).into()
+    }
+}
+
+fn try_permutation(cpu: &Computer, setting: &[i64]) -> i64 {
+    let mut input = 0;
+    for &n in setting {
+        let mut amp = cpu.clone();
+        let outputs = amp.run(&[n, input]);
+        input = outputs[0];
+    }
+    input
+}
+
+/// Partially Interpret an Intcode program up to the next output or halt instruction
+fn interpret_partial(cpu: &mut Computer, input: i64) -> Option<i64> {
+    loop {
+        match cpu.step(Some(input)) {
+            InterpretStep::Output(output) => return Some(output),
+            InterpretStep::Halt => return None,
+            _ => {}
+        }
+    }
+}
+
+fn try_feedback_loop(cpu: &Computer, setting: &[i64]) -> i64 {
+    let mut amps = Vec::new();
+    for &n in setting {
+        let mut amp = cpu.clone();
+        let _ = amp.step(Some(n));
+        amps.push(amp);
+    }
+
+    let mut input = 0;
+    loop {
+        for amp in amps.iter_mut() {
+            if let Some(output) = interpret_partial(amp, input) {
+                input = output;
+            } else {
+                // If the first amp halts, all amps will halt after it and "input" will contain the output
+                // of the last amp in the previous loop
+                return input;
+            }
+        }
+    }
+}
+
+fn part1(cpu: Computer) -> i64 {
+    permutations(&[0, 1, 2, 3, 4])
+        .map(|setting| try_permutation(&cpu, &setting))
+        .max()
+        .unwrap()
+}
+
+fn part2(cpu: Computer) -> i64 {
+    permutations(&[5, 6, 7, 8, 9])
+        .map(|setting| try_feedback_loop(&cpu, &setting))
+        .max()
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::examples! {
+        Solver;
+        amplifiers_1: "3,15,3,16,1002,16,10,16,1,16,15,15,4,15,99,0,0" => part1 = 43210;
+        amplifiers_2: "3,23,3,24,1002,24,10,24,1002,23,-1,23,\
+101,5,23,23,1,24,23,23,4,23,99,0,0" => part1 = 54321;
+        amplifiers_3: "3,31,3,32,1002,32,10,32,1001,31,-2,31,1007,31,0,33,\
+1002,33,7,33,1,33,31,31,1,32,31,31,4,31,99,0,0,0" => part1 = 65210;
+        feedback_1: "3,26,1001,26,-4,26,3,27,1002,27,2,27,1,27,26,\
+27,4,27,1001,28,-1,28,1005,28,6,99,0,0,5" => part2 = 139629729;
+        feedback_2: "3,52,1001,52,-5,52,3,53,1,52,56,54,1007,54,5,55,1005,55,26,1001,54,\
+-5,54,1105,1,12,1,53,54,53,1008,54,0,55,1001,55,1,55,2,53,55,53,4,\
+53,1001,56,-1,56,1005,56,6,99,0,0,0,0,10" => part2 = 18216;
+    }
+}
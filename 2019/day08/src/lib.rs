@@ -0,0 +1,67 @@
+use aoc::{Answer, Solution};
+use grid::Grid;
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2019;
+    const DAY: u32 = 8;
+
+    type Input<'a> = Vec<Grid<u8>>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse_input(input, W, H)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        Answer::Grid(part2(input))
+    }
+}
+
+const W: usize = 25;
+const H: usize = 6;
+
+fn parse_input(input: &str, w: usize, h: usize) -> Vec<Grid<u8>> {
+    let digits: Vec<u8> = input.trim().chars().map(|d| d.to_digit(10).unwrap() as u8).collect();
+    digits.chunks(w * h).map(|layer| Grid::from_vec(w, layer.to_vec())).collect()
+}
+
+fn count(layer: &Grid<u8>, digit: u8) -> usize {
+    layer.cells().iter().filter(|&&d| d == digit).count()
+}
+
+fn part1(input: &[Grid<u8>]) -> usize {
+    input.iter()
+        .min_by_key(|layer| count(layer, 0))
+        .map(|layer| count(layer, 1) * count(layer, 2))
+        .unwrap()
+}
+
+fn part2(input: &[Grid<u8>]) -> String {
+    let mut output = Grid::new(input[0].width(), input[0].height(), 2);
+
+    for layer in input {
+        for (pos, &d) in layer.iter() {
+            if output[pos] == 2 {
+                output[pos] = d;
+            }
+        }
+    }
+
+    output.render(|&d| if d == 0 { ' ' } else { '#' })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        assert_eq!(part1(&parse_input("123456789012", 3, 2)), 1);
+        assert_eq!(part2(&parse_input("0222112222120000", 2, 2)), " #\n# ");
+    }
+}
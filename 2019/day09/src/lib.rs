@@ -0,0 +1,45 @@
+use aoc::{Answer, Solution};
+use intcode::Computer;
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2019;
+    const DAY: u32 = 9;
+
+    type Input<'a> = Computer;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.parse().unwrap()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input.clone()).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input.clone()).into()
+    }
+}
+
+fn part1(mut cpu: Computer) -> i64 {
+    let outputs = cpu.run(&[1]);
+    outputs[0]
+}
+
+fn part2(mut cpu: Computer) -> i64 {
+    let outputs = cpu.run(&[2]);
+    outputs[0]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::examples! {
+        Solver;
+        quine: "109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99" => part1 = 109;
+        large_product: "1102,34915192,34915192,7,4,7,99,0" => part1 = 1219070632396864i64;
+        large_number: "104,1125899906842624,99" => part1 = 1125899906842624i64, part2 = 1125899906842624i64;
+    }
+}
@@ -1,26 +1,3 @@
-use std::fs;
-
-use intcode::Computer;
-
 fn main() {
-    let mem: Vec<i64> = fs::read_to_string("input")
-        .unwrap()
-        .split(',')
-        .map(|n| n.trim().parse().unwrap())
-        .collect();
-
-    let cpu = Computer::from(&mem[..]);
-
-    part1(cpu.clone());
-    part2(cpu.clone());
+    aoc::main::<aoc2019_day09::Solver>();
 }
-
-fn part1(mut cpu: Computer) {
-    let outputs = cpu.run(&[1]);
-    println!("{}", outputs[0]);
-}
-
-fn part2(mut cpu: Computer) {
-    let outputs = cpu.run(&[2]);
-    println!("{}", outputs[0]);
-}
\ No newline at end of file
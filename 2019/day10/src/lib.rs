@@ -0,0 +1,227 @@
+use std::collections::HashSet;
+use std::f32::consts::PI;
+
+use aoc::{Answer, Solution};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2019;
+    const DAY: u32 = 10;
+
+    type Input<'a> = HashSet<Point>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse_points(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+type Point = (i32, i32);
+
+fn parse_points(input: &str) -> HashSet<Point> {
+    let mut points = HashSet::new();
+    for (y, line) in input.lines().enumerate() {
+        for (x, c) in line.trim().char_indices() {
+            if c == '#' {
+                points.insert((x as i32, y as i32));
+            }
+        }
+    }
+    points
+}
+
+fn find_max_visible(points: &HashSet<Point>) -> (Point, usize) {
+    let mut point = (-1, -1);
+    let mut max_count = 0;
+
+    for &a in points {
+        let mut visible = points.clone();
+        visible.remove(&a);
+
+        for &b in points {
+            if !visible.contains(&b) { continue; }
+
+            let (mut dx, mut dy) = (b.0 - a.0, b.1 - a.1);
+            let g = math::gcd(dx, dy);
+            if g != 0 {
+                dx /= g;
+                dy /= g;
+            }
+
+            let (mut x, mut y) = b;
+            while (0..=19).contains(&x) && (0..=19).contains(&y) {
+                x += dx;
+                y += dy;
+                visible.remove(&(x, y));
+            }
+        }
+
+        let count = visible.len();
+        if count > max_count {
+            point = a;
+            max_count = count;
+        }
+    }
+
+    (point, max_count)
+}
+
+fn part1(points: &HashSet<Point>) -> usize {
+    let (_, c) = find_max_visible(points);
+
+    c
+}
+
+fn direction(a: Point, b: Point) -> f32 {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let mut angle = f32::atan2(dx as f32, -dy as f32);
+    if angle < 0.0 {
+        angle += 2.0 * PI;
+    }
+    angle
+}
+
+fn part2(points: &HashSet<Point>) -> i32 {
+    let (a, _) = find_max_visible(points);
+
+    // Find view lines (points at the same angle ordered by distance)
+    let mut angles: Vec<(f32, Vec<Point>)> = Vec::new();
+    for &b in points.iter() {
+        let angle = direction(a, b);
+        if let Some((_, line)) = angles.iter_mut().find(|(ang, _)| angle == *ang) {
+            line.push(b);
+        } else {
+            angles.push((angle, vec![b]));
+        }
+    }
+
+    // Sort view lines by angle
+    angles.sort_by(|(a1, _), (a2, _)| a1.partial_cmp(a2).unwrap());
+
+    let mut destroyed = 0;
+    loop {
+        for (_, line) in angles.iter_mut() {
+            if !line.is_empty() {
+                // Keep destroying asteroids until we hit 200
+                let last_destroyed = line.remove(0);
+                destroyed += 1;
+                if destroyed == 200 {
+                    return 100*last_destroyed.0 + last_destroyed.1;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LARGE: &str = "\
+        .#..##.###...#######
+        ##.############..##.
+        .#.######.########.#
+        .###.#######.####.#.
+        #####.##.#.##.###.##
+        ..#####..#.#########
+        ####################
+        #.####....###.#.#.##
+        ##.#################
+        #####.##.###..####..
+        ..######..##.#######
+        ####.##.####...##..#
+        .#####..#.######.###
+        ##...#.##########...
+        #.##########.#######
+        .####.#.###.###.#.##
+        ....##.##.###..#####
+        .#.#.###########.###
+        #.#.#.#####.####.###
+        ###.##.####.##.#..##";
+
+    #[test]
+    fn test_find_max_visible_1() {
+        let points = parse_points(
+            "......#.#.
+            #..#.#....
+            ..#######.
+            .#.#.###..
+            .#..#.....
+            ..#....#.#
+            #..#....#.
+            .##.#..###
+            ##...#..#.
+            .#....####"
+        );
+
+        let (p, c) = find_max_visible(&points);
+
+        assert_eq!(p, (5, 8));
+        assert_eq!(c, 33);
+    }
+
+    #[test]
+    fn test_find_max_visible_2() {
+        let points = parse_points(
+            "#.#...#.#.
+            .###....#.
+            .#....#...
+            ##.#.#.#.#
+            ....#.#.#.
+            .##..###.#
+            ..#...##..
+            ..##....##
+            ......#...
+            .####.###."
+        );
+
+        let (p, c) = find_max_visible(&points);
+
+        assert_eq!(p, (1, 2));
+        assert_eq!(c, 35);
+    }
+
+    #[test]
+    fn test_find_max_visible_3() {
+        let points = parse_points(
+            ".#..#..###
+            ####.###.#
+            ....###.#.
+            ..###.##.#
+            ##.##.#.#.
+            ....###..#
+            ..#.#..#.#
+            #..#.#.###
+            .##...##.#
+            .....#.#.."
+        );
+
+        let (p, c) = find_max_visible(&points);
+
+        assert_eq!(p, (6, 3));
+        assert_eq!(c, 41);
+    }
+
+    #[test]
+    fn test_find_max_visible_4() {
+        let points = parse_points(LARGE);
+
+        let (p, c) = find_max_visible(&points);
+
+        assert_eq!(p, (11, 13));
+        assert_eq!(c, 210);
+    }
+
+    aoc::examples! {
+        Solver;
+        large: LARGE => part1 = 210, part2 = 802;
+    }
+}
@@ -0,0 +1,44 @@
+//! The hull painting robot for Advent of Code 2019, day 11.
+
+use aoc::{Answer, Solution};
+use intcode::Computer;
+
+use robot::{Robot, BLACK, WHITE};
+
+pub mod robot;
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2019;
+    const DAY: u32 = 11;
+
+    type Input<'a> = Computer;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.parse().unwrap()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input.clone()).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input.clone())
+    }
+}
+
+fn part1(mut cpu: Computer) -> usize {
+    let mut robot = Robot::new(BLACK);
+    robot.run(&mut cpu);
+
+    robot.painted_panels()
+}
+
+fn part2(mut cpu: Computer) -> Answer {
+    let mut robot = Robot::new(WHITE);
+    robot.run(&mut cpu);
+
+    let hull = robot.hull();
+    Answer::grid(hull.bitmap(hull.bounds().unwrap()))
+}
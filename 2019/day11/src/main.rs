@@ -1,113 +1,54 @@
-use std::fs;
-use std::collections::HashMap;
+use std::env;
+use std::fs::{self, File};
+use std::path::Path;
+use std::process;
 
-use intcode::{Computer, InterpretStep};
+use aoc::Solution;
+use intcode::Computer;
 
-enum Dir { N, E, S, W, }
+use aoc2019_day11::robot::{Robot, WHITE};
+use aoc2019_day11::Solver;
 
-impl Dir {
-    fn turn(&mut self, n: i64) -> (i64, i64) {
-        *self = if n == 0 {
-            match self {
-                Dir::N => Dir::W,
-                Dir::E => Dir::N,
-                Dir::S => Dir::E,
-                Dir::W => Dir::S,
-            }
-        } else {
-            match self {
-                Dir::N => Dir::E,
-                Dir::E => Dir::S,
-                Dir::S => Dir::W,
-                Dir::W => Dir::N,
-            }
-        };
-
-        match self {
-            Dir::N => (0, -1),
-            Dir::E => (1, 0),
-            Dir::S => (0, 1),
-            Dir::W => (-1, 0),
-        }
-    }
-}
+const USAGE: &str = "Usage: day11 export <dir> [every]";
 
 fn main() {
-    let input: Vec<i64> = fs::read_to_string("input")
-        .unwrap()
-        .split(',')
-        .map(|n| n.trim().parse().unwrap())
-        .collect();
-    let cpu = Computer::from(&input[..]);
-
-    part1(cpu.clone());
-    part2(cpu.clone());
-}
-
-fn run_cycle(cpu: &mut Computer, input: i64) -> Option<(i64, i64)> {
-    let mut i = 0;
-    let mut outputs = [None; 2];
-    loop {
-        match cpu.step(Some(input)) {
-            InterpretStep::Output(output) => {
-                outputs[i].replace(output);
-                i += 1;
-                if i == 2 {
-                    break;
-                }
-            }
-            InterpretStep::Halt => return None,
-            _ => continue,
+    // `day11 export <dir> [every]` writes the registration identifier and its painting as images
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.first().map(String::as_str) == Some("export") {
+        let dir = args.get(1).unwrap_or_else(|| usage());
+        let every = match args.get(2) {
+            Some(n) => n.parse().ok().filter(|&n| n > 0).unwrap_or_else(|| usage()),
+            None => 10,
+        };
+        if args.len() > 3 {
+            usage();
         }
-    }
-    Some((outputs[0].unwrap(), outputs[1].unwrap()))
-}
-
-fn run_robot(cpu: &mut Computer, initial_color: i64) -> HashMap<(i64, i64), i64> {
-    let mut hull = HashMap::new();
-    let mut x = 0;
-    let mut y = 0;
-    let mut dir = Dir::N;
 
-    hull.insert((0, 0), initial_color);
-
-    loop {
-        let color = hull.entry((x, y)).or_insert(0);
-        if let Some((paint, turn)) = run_cycle(cpu, *color) {
-            *color = paint;
-            let (dx, dy) = dir.turn(turn);
-            x += dx;
-            y += dy;
-        } else {
-            break;
-        }
+        let cpu = Solver::parse(&aoc::read_input::<Solver>(None));
+        export(cpu, Path::new(dir), every);
+        return;
     }
 
-    hull
+    aoc::main::<Solver>();
 }
 
-fn part1(mut cpu: Computer) {
-    let hull = run_robot(&mut cpu, 0);
-
-    println!("{}", hull.len());
+fn usage() -> ! {
+    eprintln!("{}", USAGE);
+    process::exit(2);
 }
 
-fn part2(mut cpu: Computer) {
-    let hull = run_robot(&mut cpu, 1);
+fn export(mut cpu: Computer, dir: &Path, every: usize) {
+    let mut robot = Robot::new(WHITE);
+    robot.run(&mut cpu);
 
-    let ((x0, _), _) = hull.iter().min_by_key(|((x, _), _)| x).unwrap();
-    let ((_, y0), _) = hull.iter().min_by_key(|((_, y), _)| y).unwrap();
-    let ((x1, _), _) = hull.iter().max_by_key(|((x, _), _)| x).unwrap();
-    let ((_, y1), _) = hull.iter().max_by_key(|((_, y), _)| y).unwrap();
+    let hull = robot.hull();
+    let bounds = hull.bounds().unwrap();
 
-    for y in *y0..=*y1 {
-        for x in *x0..=*x1 {
-            match hull.get(&(x, y)).unwrap_or(&0) {
-                0 => print!("."),
-                1 => print!("#"),
-                _ => {},
-            }
-        }
-        println!();
+    fs::create_dir_all(dir.join("frames")).unwrap();
+    fs::write(dir.join("hull.pbm"), hull.to_pbm(bounds)).unwrap();
+    hull.write_png(File::create(dir.join("hull.png")).unwrap(), bounds, 10).unwrap();
+
+    for (i, frame) in robot.frames(every).iter().enumerate() {
+        fs::write(dir.join("frames").join(format!("frame_{:04}.pbm", i)), frame).unwrap();
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,298 @@
+//! A simulation of the hull painting robot that records every panel it
+//! paints, so the hull can be inspected, replayed and exported as images.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::io::{self, Write};
+
+use geometry::{Dir4, Point2, Turtle};
+use intcode::{Computer, InterpretStep};
+
+pub type Panel = Point2;
+
+pub const BLACK: i64 = 0;
+pub const WHITE: i64 = 1;
+
+/// The inclusive range of panels to show when rendering a hull.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Bounds {
+    pub min: Panel,
+    pub max: Panel,
+}
+
+impl Bounds {
+    pub fn width(&self) -> usize {
+        (self.max.x - self.min.x + 1) as usize
+    }
+
+    pub fn height(&self) -> usize {
+        (self.max.y - self.min.y + 1) as usize
+    }
+}
+
+/// The colors of all panels the robot has visited. Unvisited panels are black.
+#[derive(Clone, Default, Debug)]
+pub struct Hull {
+    panels: HashMap<Panel, i64>,
+}
+
+impl Hull {
+    pub fn color(&self, panel: Panel) -> i64 {
+        self.panels.get(&panel).copied().unwrap_or(BLACK)
+    }
+
+    /// The number of panels that have a color, including panels painted black.
+    pub fn len(&self) -> usize {
+        self.panels.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.panels.is_empty()
+    }
+
+    /// The smallest bounds containing every visited panel.
+    pub fn bounds(&self) -> Option<Bounds> {
+        let x0 = self.panels.keys().map(|p| p.x).min()?;
+        let y0 = self.panels.keys().map(|p| p.y).min()?;
+        let x1 = self.panels.keys().map(|p| p.x).max()?;
+        let y1 = self.panels.keys().map(|p| p.y).max()?;
+        Some(Bounds { min: Point2::new(x0, y0), max: Point2::new(x1, y1) })
+    }
+
+    /// Return the rows of panels within the bounds, `true` meaning white.
+    pub fn bitmap(&self, bounds: Bounds) -> Vec<Vec<bool>> {
+        (bounds.min.y..=bounds.max.y)
+            .map(|y| {
+                (bounds.min.x..=bounds.max.x)
+                    .map(|x| self.color(Point2::new(x, y)) == WHITE)
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Render the hull as a plain (ASCII) PBM image.
+    pub fn to_pbm(&self, bounds: Bounds) -> String {
+        let mut pbm = format!("P1\n{} {}\n", bounds.width(), bounds.height());
+        for row in self.bitmap(bounds) {
+            let line: Vec<&str> = row.iter().map(|&white| if white { "0" } else { "1" }).collect();
+            pbm.push_str(&line.join(" "));
+            pbm.push('\n');
+        }
+        pbm
+    }
+
+    /// Write the hull as a grayscale PNG image, drawing each panel as a
+    /// `scale` by `scale` square.
+    pub fn write_png<W: Write>(&self, w: W, bounds: Bounds, scale: usize) -> io::Result<()> {
+        let (width, height) = (bounds.width() * scale, bounds.height() * scale);
+
+        let mut data = Vec::with_capacity(width * height);
+        for row in self.bitmap(bounds) {
+            let line: Vec<u8> = row
+                .iter()
+                .flat_map(|&white| vec![if white { 255 } else { 0 }; scale])
+                .collect();
+            for _ in 0..scale {
+                data.extend_from_slice(&line);
+            }
+        }
+
+        let mut encoder = png::Encoder::new(w, width as u32, height as u32);
+        encoder.set_color(png::ColorType::Grayscale);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder.write_header()?.write_image_data(&data)?;
+        Ok(())
+    }
+}
+
+impl fmt::Display for Hull {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(bounds) = self.bounds() {
+            for row in self.bitmap(bounds) {
+                let line: String = row.iter().map(|&white| if white { '#' } else { '.' }).collect();
+                writeln!(f, "{}", line)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A single action of the robot: painting a panel.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Paint {
+    pub panel: Panel,
+    pub color: i64,
+}
+
+/// The robot's position and heading, the hull it is painting and the
+/// history of everything it painted.
+#[derive(Clone, Debug)]
+pub struct Robot {
+    turtle: Turtle,
+    initial_color: i64,
+    hull: Hull,
+    paint_counts: HashMap<Panel, usize>,
+    history: Vec<Paint>,
+}
+
+impl Robot {
+    /// Construct a robot at the origin, facing up, standing on a panel of
+    /// the given color.
+    pub fn new(initial_color: i64) -> Self {
+        let mut hull = Hull::default();
+        hull.panels.insert(Point2::ORIGIN, initial_color);
+
+        Self {
+            turtle: Turtle::new(Point2::ORIGIN, Dir4::N),
+            initial_color,
+            hull,
+            paint_counts: HashMap::new(),
+            history: Vec::new(),
+        }
+    }
+
+    pub fn position(&self) -> Panel {
+        self.turtle.pos
+    }
+
+    pub fn dir(&self) -> Dir4 {
+        self.turtle.dir
+    }
+
+    pub fn hull(&self) -> &Hull {
+        &self.hull
+    }
+
+    /// Every panel painted, in order.
+    pub fn history(&self) -> &[Paint] {
+        &self.history
+    }
+
+    /// The number of times a panel has been painted.
+    pub fn paint_count(&self, panel: Panel) -> usize {
+        self.paint_counts.get(&panel).copied().unwrap_or(0)
+    }
+
+    /// The number of panels painted at least once.
+    pub fn painted_panels(&self) -> usize {
+        self.paint_counts.len()
+    }
+
+    /// Paint the current panel, then turn (0 for left, 1 for right) and move forward.
+    pub fn step(&mut self, color: i64, turn: i64) {
+        let panel = self.turtle.pos;
+        self.hull.panels.insert(panel, color);
+        *self.paint_counts.entry(panel).or_insert(0) += 1;
+        self.history.push(Paint { panel, color });
+
+        if turn == 0 {
+            self.turtle.turn_left();
+        } else {
+            self.turtle.turn_right();
+        }
+        self.turtle.forward(1);
+        self.hull.panels.entry(self.turtle.pos).or_insert(BLACK);
+    }
+
+    /// Let the program control the robot until it halts.
+    pub fn run(&mut self, cpu: &mut Computer) {
+        while let Some((paint, turn)) = run_cycle(cpu, self.hull.color(self.turtle.pos)) {
+            self.step(paint, turn);
+        }
+    }
+
+    /// Reconstruct the hull as it was after the first `steps` paints.
+    pub fn replay(&self, steps: usize) -> Hull {
+        let mut hull = Hull::default();
+        hull.panels.insert(Point2::ORIGIN, self.initial_color);
+        for paint in self.history.iter().take(steps) {
+            hull.panels.insert(paint.panel, paint.color);
+        }
+        hull
+    }
+
+    /// Render every `every`th step of the robot's history as a PBM image,
+    /// ending with the final hull. All frames share the final hull's bounds.
+    pub fn frames(&self, every: usize) -> Vec<String> {
+        let bounds = match self.hull.bounds() {
+            Some(bounds) => bounds,
+            None => return Vec::new(),
+        };
+
+        let mut steps: Vec<usize> = (0..self.history.len()).step_by(every.max(1)).collect();
+        steps.push(self.history.len());
+        steps.into_iter().map(|steps| self.replay(steps).to_pbm(bounds)).collect()
+    }
+}
+
+/// Run the program until it outputs a color and a turn, or return `None` if it halts.
+fn run_cycle(cpu: &mut Computer, input: i64) -> Option<(i64, i64)> {
+    let mut i = 0;
+    let mut outputs = [None; 2];
+    loop {
+        match cpu.step(Some(input)) {
+            InterpretStep::Output(output) => {
+                outputs[i].replace(output);
+                i += 1;
+                if i == 2 {
+                    break;
+                }
+            }
+            InterpretStep::Halt => return None,
+            _ => continue,
+        }
+    }
+    Some((outputs[0].unwrap(), outputs[1].unwrap()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example_robot() -> Robot {
+        let mut robot = Robot::new(BLACK);
+        for &(color, turn) in &[(1, 0), (0, 0), (1, 0), (1, 0), (0, 1), (1, 0), (1, 0)] {
+            robot.step(color, turn);
+        }
+        robot
+    }
+
+    #[test]
+    fn test_example() {
+        let robot = example_robot();
+
+        assert_eq!(robot.painted_panels(), 6);
+        assert_eq!(robot.paint_count(Point2::new(0, 0)), 2);
+        assert_eq!(robot.paint_count(Point2::new(-1, 0)), 1);
+        assert_eq!(robot.paint_count(Point2::new(5, 5)), 0);
+        assert_eq!(robot.position(), Point2::new(0, -1));
+        assert_eq!(robot.dir(), Dir4::W);
+        assert_eq!(robot.hull().to_string(), "..#\n..#\n##.\n");
+    }
+
+    #[test]
+    fn test_replay() {
+        let robot = example_robot();
+
+        assert_eq!(robot.history()[0], Paint { panel: Point2::ORIGIN, color: 1 });
+        assert_eq!(robot.replay(0).color(Point2::ORIGIN), BLACK);
+        assert_eq!(robot.replay(1).color(Point2::ORIGIN), WHITE);
+        assert_eq!(robot.replay(7).to_string(), robot.hull().to_string());
+
+        let frames = robot.frames(3);
+        assert_eq!(frames.len(), 4);
+        assert_eq!(frames[3], robot.hull().to_pbm(robot.hull().bounds().unwrap()));
+    }
+
+    #[test]
+    fn test_export() {
+        let robot = example_robot();
+        let bounds = robot.hull().bounds().unwrap();
+
+        assert_eq!(robot.hull().to_pbm(bounds), "P1\n3 3\n1 1 0\n1 1 0\n0 0 1\n");
+
+        let mut png = Vec::new();
+        robot.hull().write_png(&mut png, bounds, 2).unwrap();
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+    }
+}
@@ -0,0 +1,144 @@
+use aoc::{Answer, Solution};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2019;
+    const DAY: u32 = 12;
+
+    type Input<'a> = System;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        scan::or_panic(System::new(input))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input.clone(), STEPS).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input.clone()).into()
+    }
+}
+
+#[derive(Clone)]
+struct Moon {
+    pos: [i64; 3],
+    vel: [i64; 3],
+}
+
+fn sign(n: i64) -> i64 {
+    if n < 0 { -1 }
+    else if n > 0 { 1 }
+    else { 0 }
+}
+
+impl Moon {
+    fn energy(&self) -> i64 {
+        let pot: i64 = self.pos.iter().map(|n| n.abs()).sum();
+        let kin: i64 = self.vel.iter().map(|n| n.abs()).sum();
+        pot * kin
+    }
+}
+
+/// The position and velocity of every moon along one axis.
+type AxisState = [[i64; 2]; 4];
+
+/// Apply gravity and velocity along one axis, which does not depend on the
+/// other axes.
+fn step_axis(state: &AxisState) -> AxisState {
+    let mut next = *state;
+    for (moon, [pos, vel]) in next.iter_mut().enumerate() {
+        *vel += state.iter().map(|other| sign(other[0] - state[moon][0])).sum::<i64>();
+        *pos += *vel;
+    }
+    next
+}
+
+#[derive(Clone)]
+pub struct System {
+    moons: Vec<Moon>,
+}
+
+impl System {
+    fn new(input: &str) -> Result<Self, scan::Error> {
+        let moons = scan::lines(input, |line| {
+            let (x, y, z) = scan::scan!(line, "<x={}, y={}, z={}>" => i64, i64, i64)?;
+            Ok(Moon {
+                pos: [x, y, z],
+                vel: [0, 0, 0],
+            })
+        })?;
+
+        Ok(Self { moons })
+    }
+
+    fn step(&mut self) {
+        for axis in 0..3 {
+            let next = step_axis(&self.axis_state(axis));
+            for (moon, [pos, vel]) in self.moons.iter_mut().zip(next) {
+                moon.pos[axis] = pos;
+                moon.vel[axis] = vel;
+            }
+        }
+    }
+
+    fn energy(&self) -> i64 {
+        self.moons.iter().map(|moon| moon.energy()).sum()
+    }
+
+    fn axis_state(&self, axis: usize) -> AxisState {
+        let mut state = [[0; 2]; 4];
+        for (s, moon) in state.iter_mut().zip(self.moons.iter()) {
+            *s = [moon.pos[axis], moon.vel[axis]];
+        }
+        state
+    }
+}
+
+const STEPS: usize = 1000;
+
+fn part1(mut system: System, steps: usize) -> i64 {
+    for _ in 0..steps {
+        system.step();
+    }
+
+    system.energy()
+}
+
+fn part2(system: System) -> u64 {
+    let steps: Vec<u64> = (0..3)
+        .map(|axis| cycle::brent(system.axis_state(axis), step_axis).len as u64)
+        .collect();
+
+    math::lcm_all(&steps).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_1: &str = "\
+<x=-1, y=0, z=2>
+<x=2, y=-10, z=-7>
+<x=4, y=-8, z=8>
+<x=3, y=5, z=-1>";
+
+    const EXAMPLE_2: &str = "\
+<x=-8, y=-10, z=0>
+<x=5, y=5, z=10>
+<x=2, y=-7, z=3>
+<x=9, y=-8, z=-3>";
+
+    #[test]
+    fn test_energy() {
+        assert_eq!(part1(Solver::parse(EXAMPLE_1), 10), 179);
+        assert_eq!(part1(Solver::parse(EXAMPLE_2), 100), 1940);
+    }
+
+    aoc::examples! {
+        Solver;
+        example_1: EXAMPLE_1 => part2 = 2772;
+        example_2: EXAMPLE_2 => part2 = 4686774924u64;
+    }
+}
@@ -0,0 +1,372 @@
+//! A headless arcade cabinet: the screen, score and ball tracking of the
+//! Intcode breakout game, driven by a pluggable joystick and renderer.
+
+use std::fmt;
+use std::io::{self, BufRead, Write};
+use std::thread;
+use std::time::Duration;
+
+use intcode::{Computer, InterpretStep};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Tile {
+    Empty,
+    Wall,
+    Block,
+    Paddle,
+    Ball,
+}
+
+impl Tile {
+    fn new(id: i64) -> Self {
+        match id {
+            0 => Tile::Empty,
+            1 => Tile::Wall,
+            2 => Tile::Block,
+            3 => Tile::Paddle,
+            4 => Tile::Ball,
+            n => panic!("Unknown tile id: {}", n),
+        }
+    }
+
+    fn to_char(self) -> char {
+        match self {
+            Tile::Empty => ' ',
+            Tile::Wall => '#',
+            Tile::Block => '=',
+            Tile::Paddle => '_',
+            Tile::Ball => 'o',
+        }
+    }
+}
+
+/// The state of the cabinet: the tile framebuffer, the score and the
+/// positions of the ball and the paddle.
+#[derive(Default)]
+pub struct Cabinet {
+    tiles: Vec<Vec<Tile>>,
+    score: i64,
+    trajectory: Vec<(i64, i64)>,
+    paddle: Option<(i64, i64)>,
+}
+
+impl Cabinet {
+    /// Process an (x, y, id) triple output by the game.
+    pub fn draw(&mut self, x: i64, y: i64, id: i64) {
+        if x == -1 && y == 0 {
+            self.score = id;
+            return;
+        }
+
+        let tile = Tile::new(id);
+        let (col, row) = (x as usize, y as usize);
+        if row >= self.tiles.len() {
+            self.tiles.resize(row + 1, Vec::new());
+        }
+        let line = &mut self.tiles[row];
+        if col >= line.len() {
+            line.resize(col + 1, Tile::Empty);
+        }
+        line[col] = tile;
+
+        match tile {
+            Tile::Ball => self.trajectory.push((x, y)),
+            Tile::Paddle => self.paddle = Some((x, y)),
+            _ => {}
+        }
+    }
+
+    pub fn score(&self) -> i64 {
+        self.score
+    }
+
+    pub fn get(&self, x: i64, y: i64) -> Tile {
+        if x < 0 || y < 0 {
+            return Tile::Empty;
+        }
+        self.tiles
+            .get(y as usize)
+            .and_then(|row| row.get(x as usize))
+            .copied()
+            .unwrap_or(Tile::Empty)
+    }
+
+    pub fn count(&self, tile: Tile) -> usize {
+        self.tiles.iter().flatten().filter(|&&t| t == tile).count()
+    }
+
+    pub fn width(&self) -> i64 {
+        self.tiles.iter().map(|row| row.len()).max().unwrap_or(0) as i64
+    }
+
+    pub fn ball(&self) -> Option<(i64, i64)> {
+        self.trajectory.last().copied()
+    }
+
+    /// All positions the ball has been drawn at, oldest first.
+    pub fn trajectory(&self) -> &[(i64, i64)] {
+        &self.trajectory
+    }
+
+    /// The direction the ball moved in since it was last drawn.
+    pub fn velocity(&self) -> Option<(i64, i64)> {
+        match self.trajectory.as_slice() {
+            [.., (x0, y0), (x1, y1)] => Some((x1 - x0, y1 - y0)),
+            _ => None,
+        }
+    }
+
+    pub fn paddle(&self) -> Option<(i64, i64)> {
+        self.paddle
+    }
+}
+
+impl fmt::Display for Cabinet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Score: {}", self.score)?;
+        for row in &self.tiles {
+            let line: String = row.iter().map(|t| t.to_char()).collect();
+            writeln!(f, "{}", line)?;
+        }
+        Ok(())
+    }
+}
+
+/// Decides how to tilt the joystick: -1 for left, 0 for neutral and 1 for right.
+pub trait Joystick {
+    fn tilt(&mut self, cabinet: &Cabinet) -> i64;
+}
+
+/// Keep the paddle directly below the ball.
+pub struct FollowBall;
+
+impl Joystick for FollowBall {
+    fn tilt(&mut self, cabinet: &Cabinet) -> i64 {
+        match (cabinet.ball(), cabinet.paddle()) {
+            (Some((bx, _)), Some((px, _))) => (bx - px).signum(),
+            _ => 0,
+        }
+    }
+}
+
+/// Move the paddle to where the ball will come down, bouncing it off the
+/// side walls.
+pub struct Predictive;
+
+impl Predictive {
+    /// Predict the column the ball will be in when it reaches the row above the paddle.
+    pub fn intercept(cabinet: &Cabinet) -> Option<i64> {
+        let (bx, by) = cabinet.ball()?;
+        let (_, py) = cabinet.paddle()?;
+        let (dx, dy) = match cabinet.velocity() {
+            Some(velocity) if velocity.1 > 0 => velocity,
+            _ => return Some(bx),
+        };
+
+        // the playing field lies between the walls in the first and last column
+        let (lo, hi) = (1, cabinet.width() - 2);
+        if hi <= lo {
+            return Some(bx);
+        }
+        let steps = (py - 1 - by) / dy;
+        let period = 2 * (hi - lo);
+        let mut offset = (bx + dx * steps - lo).rem_euclid(period);
+        if offset > hi - lo {
+            offset = period - offset;
+        }
+        Some(lo + offset)
+    }
+}
+
+impl Joystick for Predictive {
+    fn tilt(&mut self, cabinet: &Cabinet) -> i64 {
+        match (Self::intercept(cabinet), cabinet.paddle()) {
+            (Some(x), Some((px, _))) => (x - px).signum(),
+            _ => 0,
+        }
+    }
+}
+
+/// Read moves from stdin: `a` for left, `d` for right, anything else for neutral.
+pub struct Keyboard;
+
+impl Joystick for Keyboard {
+    fn tilt(&mut self, _cabinet: &Cabinet) -> i64 {
+        print!("move [a/s/d]: ");
+        io::stdout().flush().unwrap();
+
+        let mut line = String::new();
+        io::stdin().lock().read_line(&mut line).unwrap();
+        match line.trim() {
+            "a" => -1,
+            "d" => 1,
+            _ => 0,
+        }
+    }
+}
+
+/// Shows a frame every time the game waits for joystick input.
+pub trait Renderer {
+    fn frame(&mut self, cabinet: &Cabinet);
+}
+
+/// Does not render anything.
+pub struct Headless;
+
+impl Renderer for Headless {
+    fn frame(&mut self, _cabinet: &Cabinet) {}
+}
+
+/// Draws frames to the terminal using ANSI escape codes.
+pub struct Terminal {
+    delay: Duration,
+    cleared: bool,
+}
+
+impl Terminal {
+    /// Construct a renderer that shows at most `fps` frames per second,
+    /// or renders as fast as possible if `fps` is 0.
+    pub fn new(fps: u32) -> Self {
+        let delay = if fps == 0 {
+            Duration::from_secs(0)
+        } else {
+            Duration::from_secs(1) / fps
+        };
+        Self { delay, cleared: false }
+    }
+}
+
+impl Renderer for Terminal {
+    fn frame(&mut self, cabinet: &Cabinet) {
+        if !self.cleared {
+            print!("\x1b[2J");
+            self.cleared = true;
+        }
+        print!("\x1b[H{}", cabinet);
+        io::stdout().flush().unwrap();
+        thread::sleep(self.delay);
+    }
+}
+
+/// An Intcode arcade game together with its cabinet.
+pub struct Arcade {
+    cpu: Computer,
+    cabinet: Cabinet,
+}
+
+impl Arcade {
+    pub fn new(cpu: Computer) -> Self {
+        Self {
+            cpu,
+            cabinet: Cabinet::default(),
+        }
+    }
+
+    pub fn cabinet(&self) -> &Cabinet {
+        &self.cabinet
+    }
+
+    /// Run the game until it halts and return the final score.
+    pub fn run(&mut self, joystick: &mut dyn Joystick, renderer: &mut dyn Renderer) -> i64 {
+        let mut out = [0; 3];
+        let mut i = 0;
+
+        loop {
+            let input = if self.cpu.wants_input() {
+                renderer.frame(&self.cabinet);
+                Some(joystick.tilt(&self.cabinet))
+            } else {
+                None
+            };
+
+            match self.cpu.step(input) {
+                InterpretStep::Output(output) => {
+                    out[i] = output;
+                    i += 1;
+
+                    if i == 3 {
+                        self.cabinet.draw(out[0], out[1], out[2]);
+                        i = 0;
+                    }
+                }
+                InterpretStep::Halt => break,
+                _ => {}
+            }
+        }
+
+        renderer.frame(&self.cabinet);
+        self.cabinet.score
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_draw() {
+        let mut cabinet = Cabinet::default();
+        cabinet.draw(0, 0, 1);
+        cabinet.draw(2, 1, 2);
+        cabinet.draw(1, 2, 3);
+        cabinet.draw(-1, 0, 42);
+
+        assert_eq!(cabinet.get(0, 0), Tile::Wall);
+        assert_eq!(cabinet.get(1, 0), Tile::Empty);
+        assert_eq!(cabinet.get(2, 1), Tile::Block);
+        assert_eq!(cabinet.paddle(), Some((1, 2)));
+        assert_eq!(cabinet.count(Tile::Block), 1);
+        assert_eq!(cabinet.score(), 42);
+        assert_eq!(cabinet.to_string(), "Score: 42\n#\n  =\n _\n");
+    }
+
+    #[test]
+    fn test_trajectory() {
+        let mut cabinet = Cabinet::default();
+        cabinet.draw(3, 3, 4);
+        assert_eq!(cabinet.velocity(), None);
+
+        cabinet.draw(3, 3, 0);
+        cabinet.draw(4, 4, 4);
+        assert_eq!(cabinet.trajectory(), [(3, 3), (4, 4)]);
+        assert_eq!(cabinet.ball(), Some((4, 4)));
+        assert_eq!(cabinet.velocity(), Some((1, 1)));
+    }
+
+    #[test]
+    fn test_intercept() {
+        let mut cabinet = Cabinet::default();
+        // a field with walls in columns 0 and 6, paddle in row 10
+        cabinet.draw(0, 0, 1);
+        cabinet.draw(6, 0, 1);
+        cabinet.draw(3, 10, 3);
+
+        // straight down the right side, bouncing once off the right wall
+        cabinet.draw(3, 4, 4);
+        cabinet.draw(4, 5, 4);
+        assert_eq!(Predictive::intercept(&cabinet), Some(2));
+
+        // moving up: stay below the ball
+        cabinet.draw(5, 4, 4);
+        assert_eq!(Predictive::intercept(&cabinet), Some(5));
+    }
+
+    #[test]
+    fn test_run_headless() {
+        // draw a paddle at (0, 2) and a ball at (1, 2), then output the joystick input as the score
+        let program = [
+            104,0,104,2,104,3,
+            104,1,104,2,104,4,
+            3,100,
+            104,-1,104,0,4,100,
+            99,
+        ];
+
+        let mut arcade = Arcade::new(Computer::from(&program[..]));
+        assert_eq!(arcade.run(&mut FollowBall, &mut Headless), 1);
+        assert_eq!(arcade.cabinet().ball(), Some((1, 2)));
+
+        let mut arcade = Arcade::new(Computer::from(&program[..]));
+        assert_eq!(arcade.run(&mut Predictive, &mut Headless), 1);
+    }
+}
@@ -0,0 +1,42 @@
+//! The Intcode arcade cabinet for Advent of Code 2019, day 13.
+
+use aoc::{Answer, Solution};
+use intcode::Computer;
+
+use arcade::{Arcade, FollowBall, Headless, Tile};
+
+pub mod arcade;
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2019;
+    const DAY: u32 = 13;
+
+    type Input<'a> = Computer;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.parse().unwrap()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input.clone()).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input.clone()).into()
+    }
+}
+
+fn part1(cpu: Computer) -> usize {
+    let mut arcade = Arcade::new(cpu);
+    arcade.run(&mut FollowBall, &mut Headless);
+
+    arcade.cabinet().count(Tile::Block)
+}
+
+fn part2(mut cpu: Computer) -> i64 {
+    cpu.memory[0] = 2;
+
+    Arcade::new(cpu).run(&mut FollowBall, &mut Headless)
+}
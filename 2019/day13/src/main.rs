@@ -1,103 +1,49 @@
-use std::fs;
-use std::collections::HashMap;
+use std::env;
+use std::process;
 
-use intcode::{Computer, InterpretStep};
+use aoc::Solution;
+use intcode::Computer;
 
-fn main() {
-    let input: Vec<i64> = fs::read_to_string("input")
-        .unwrap()
-        .split(',')
-        .map(|n| n.trim().parse().unwrap())
-        .collect();
-
-    let cpu = Computer::from(&input[..]);
-
-    part1(cpu.clone());
-    part2(cpu.clone());
-}
-
-fn get_output(cpu: &mut Computer) -> Option<(i64, i64, i64)> {
-    let mut out = [-1; 3];
-    let mut i = 0;
+use aoc2019_day13::arcade::{Arcade, FollowBall, Joystick, Keyboard, Predictive, Terminal};
+use aoc2019_day13::Solver;
 
-    loop {
-        match cpu.step(None) {
-            InterpretStep::Output(output) => {
-                out[i] = output;
-                i += 1;
+const USAGE: &str = "Usage: day13 play [follow|predict|keyboard] [fps]";
 
-                if i == 3 {
-                    return Some((out[0], out[1], out[2]));
-                }
-            }
-            InterpretStep::Halt => return None,
-            _ => {}
+fn main() {
+    // `day13 play [follow|predict|keyboard] [fps]` shows the game in the terminal
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.first().map(String::as_str) == Some("play") {
+        let joystick: Box<dyn Joystick> = match args.get(1).map_or("follow", String::as_str) {
+            "follow" => Box::new(FollowBall),
+            "predict" => Box::new(Predictive),
+            "keyboard" => Box::new(Keyboard),
+            _ => usage(),
+        };
+        let fps = match args.get(2) {
+            Some(fps) => fps.parse().unwrap_or_else(|_| usage()),
+            None => 30,
+        };
+        if args.len() > 3 {
+            usage();
         }
-    }
-}
 
-fn part1(mut cpu: Computer) {
-    let mut screen = HashMap::new();
-
-    loop {
-        if let Some((x, y, id)) = get_output(&mut cpu) {
-            *screen.entry((x, y)).or_insert(0) = id;
-        } else {
-            break;
-        }
+        let cpu = Solver::parse(&aoc::read_input::<Solver>(None));
+        play(cpu, joystick, fps);
+        return;
     }
 
-    let count = screen.values().filter(|&&id| id == 2).count();
-    println!("{}", count);
+    aoc::main::<Solver>();
 }
 
-fn part2(mut cpu: Computer) {
-    cpu.memory[0] = 2;
-
-    let mut score = 0;
-    let mut ball = (0, 0);
-    let mut paddle = (0, 0);
-    let mut input = None;
-    let mut x = None;
-    let mut y = None;
-
-    loop {
-        if cpu.wants_input() {
-            if ball.0 < paddle.0 {
-                input.replace(-1);
-            } else if ball.0 == paddle.0 {
-                input.replace(0);
-            } else if ball.0 > paddle.0 {
-                input.replace(1);
-            }
-        }
-
-        match cpu.step(input) {
-            InterpretStep::Output(n) => {
-                if x.is_none() {
-                    x.replace(n);
-                } else if y.is_none() {
-                    y.replace(n);
-                } else {
-                    let xx = x.unwrap();
-                    let yy = y.unwrap();
-
-                    if xx == -1 && yy == 0 {
-                        score = n;
-                    } else if n == 3 {
-                        paddle = (xx, yy);
-                    } else if n == 4 {
-                        ball = (xx, yy);
-                    }
+fn usage() -> ! {
+    eprintln!("{}", USAGE);
+    process::exit(2);
+}
 
-                    let _ = x.take();
-                    let _ = y.take();
-                }
-            }
-            InterpretStep::Halt => break,
-            _ => {}
-        }
-    }
+fn play(mut cpu: Computer, mut joystick: Box<dyn Joystick>, fps: u32) {
+    cpu.memory[0] = 2;
 
-    println!("{}", score);
+    let mut terminal = Terminal::new(fps);
+    let score = Arcade::new(cpu).run(joystick.as_mut(), &mut terminal);
+    println!("Game over, final score: {}", score);
 }
@@ -0,0 +1,79 @@
+use aoc::{Answer, Solution};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2019;
+    const DAY: u32 = 16;
+    const PARTS: u32 = 1;
+
+    type Input<'a> = Vec<u8>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+            .trim()
+            .chars()
+            .map(|c| c.to_digit(10).unwrap() as u8)
+            .collect()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(_input: &Self::Input<'_>) -> Answer {
+        Answer::Unsolved
+    }
+}
+
+fn make_pattern(n: usize, pattern: &mut [i8]) {
+    const BASE: &[i8] = &[0, 1, 0, -1];
+    let mut j = 0;
+    for (i, p) in pattern.iter_mut().enumerate() {
+        *p = BASE[j];
+        if (i + 1) % n == 0 {
+            j += 1;
+            j %= BASE.len();
+        }
+    }
+}
+
+fn fft(input: &[u8]) -> Vec<u8> {
+    let len = input.len();
+    let mut output = vec![0; len];
+    let mut pattern = vec![0; len + 1];
+
+    for (i, out) in output.iter_mut().enumerate() {
+        make_pattern(i + 1, &mut pattern);
+
+        let n: i32 = input
+            .iter()
+            .zip(pattern[1..].iter())
+            .map(|(&i, &p)| (i as i32) * (p as i32))
+            .sum();
+
+        *out = (n.abs() % 10) as u8;
+    }
+
+    output
+}
+
+fn part1(input: &[u8]) -> String {
+    let mut input = input.to_vec();
+    for _ in 0..100 {
+        input = fft(&input);
+    }
+    input[..8].iter().map(|d| d.to_string()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::examples! {
+        Solver;
+        example_1: "80871224585914546619083218645595" => part1 = "24176176";
+        example_2: "19617804207202209144916044189917" => part1 = "73745418";
+        example_3: "69317163492948606335995924319873" => part1 = "52432133";
+    }
+}
@@ -0,0 +1,265 @@
+use aoc::{Answer, Solution};
+use intcode::Computer;
+
+/// The maximum length of a routine, not counting the newline.
+const MAX_ROUTINE_LEN: usize = 20;
+const FUNCTION_NAMES: [&str; 3] = ["A", "B", "C"];
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2019;
+    const DAY: u32 = 17;
+
+    type Input<'a> = Computer;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.parse().unwrap()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input.clone()).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input.clone()).into()
+    }
+}
+
+fn camera_image(cpu: &mut Computer) -> Vec<Vec<char>> {
+    let output = cpu.run(&[]);
+
+    output.split(|&n| n == 10)
+        .take_while(|row| !row.is_empty())
+        .map(|row| row.iter().map(|&n| (n as u8).into()).collect())
+        .collect()
+}
+
+fn is_scaffold(image: &[Vec<char>], x: i64, y: i64) -> bool {
+    if x < 0 || y < 0 {
+        return false;
+    }
+    image.get(y as usize)
+        .and_then(|row| row.get(x as usize))
+        .is_some_and(|&c| c != '.')
+}
+
+/// Walk the scaffold from the robot's position to its end, turning only
+/// at corners, and return the path as turn and forward tokens.
+fn scaffold_path(image: &[Vec<char>]) -> Vec<String> {
+    let (mut x, mut y, mut dir) = image.iter()
+        .enumerate()
+        .find_map(|(y, row)| {
+            row.iter().position(|c| "^>v<".contains(*c)).map(|x| {
+                let dir = match row[x] {
+                    '^' => (0, -1),
+                    '>' => (1, 0),
+                    'v' => (0, 1),
+                    _ => (-1, 0),
+                };
+                (x as i64, y as i64, dir)
+            })
+        })
+        .unwrap();
+
+    let mut path = Vec::new();
+    loop {
+        let (dx, dy) = dir;
+        let left = (dy, -dx);
+        let right = (-dy, dx);
+
+        if is_scaffold(image, x + left.0, y + left.1) {
+            path.push("L".to_string());
+            dir = left;
+        } else if is_scaffold(image, x + right.0, y + right.1) {
+            path.push("R".to_string());
+            dir = right;
+        } else {
+            break;
+        }
+
+        let mut steps = 0;
+        while is_scaffold(image, x + dir.0, y + dir.1) {
+            x += dir.0;
+            y += dir.1;
+            steps += 1;
+        }
+        path.push(steps.to_string());
+    }
+
+    path
+}
+
+/// The length of a routine when its tokens are separated by commas.
+fn routine_len<S: AsRef<str>>(tokens: &[S]) -> usize {
+    tokens.iter().map(|t| t.as_ref().len()).sum::<usize>() + tokens.len().saturating_sub(1)
+}
+
+fn compress_from<'a, S: AsRef<str> + PartialEq>(
+    tokens: &'a [S],
+    start: usize,
+    max_functions: usize,
+    max_len: usize,
+    main: &mut Vec<usize>,
+    functions: &mut Vec<&'a [S]>,
+) -> bool {
+    if start == tokens.len() {
+        return true;
+    }
+    // every call adds its name and a comma to the main routine
+    if 2 * (main.len() + 1) - 1 > max_len {
+        return false;
+    }
+
+    let rest = &tokens[start..];
+
+    for i in 0..functions.len() {
+        let function = functions[i];
+        if rest.starts_with(function) {
+            main.push(i);
+            if compress_from(tokens, start + function.len(), max_functions, max_len, main, functions) {
+                return true;
+            }
+            main.pop();
+        }
+    }
+
+    if functions.len() < max_functions {
+        for end in 1..=rest.len() {
+            let function = &rest[..end];
+            if routine_len(function) > max_len {
+                break;
+            }
+
+            functions.push(function);
+            main.push(functions.len() - 1);
+            if compress_from(tokens, start + end, max_functions, max_len, main, functions) {
+                return true;
+            }
+            main.pop();
+            functions.pop();
+        }
+    }
+
+    false
+}
+
+/// Split a token sequence into at most `max_functions` functions and a main
+/// routine calling them by index, so that no routine is longer than `max_len`
+/// characters when written with commas. Returns `None` if no such split exists.
+fn compress<S: AsRef<str> + PartialEq>(
+    tokens: &[S],
+    max_functions: usize,
+    max_len: usize,
+) -> Option<(Vec<usize>, Vec<&[S]>)> {
+    let mut main = Vec::new();
+    let mut functions = Vec::new();
+    if compress_from(tokens, 0, max_functions, max_len, &mut main, &mut functions) {
+        Some((main, functions))
+    } else {
+        None
+    }
+}
+
+fn part1(mut cpu: Computer) -> usize {
+    let image = camera_image(&mut cpu);
+
+    let mut total = 0;
+    let h = image.len();
+    for y in 1..(h-1) {
+        let w = image[y].len();
+        for x in 1..(w-1) {
+            if image[y][x] == '#' && image[y][x-1] == '#' && image[y][x+1] == '#'
+                && image[y-1][x] == '#' && image[y+1][x] == '#'
+            {
+                total += x * y;
+            }
+        }
+    }
+
+    total
+}
+
+fn part2(mut cpu: Computer) -> i64 {
+    let image = camera_image(&mut cpu.clone());
+    let path = scaffold_path(&image);
+    let (main, functions) = compress(&path, FUNCTION_NAMES.len(), MAX_ROUTINE_LEN).unwrap();
+
+    let mut routines = vec![
+        main.iter().map(|&i| FUNCTION_NAMES[i]).collect::<Vec<_>>().join(","),
+    ];
+    routines.extend(functions.iter().map(|f| f.join(",")));
+    // pad unused functions and decline the continuous video feed
+    routines.resize(1 + FUNCTION_NAMES.len(), String::new());
+    routines.push("n".to_string());
+
+    let input: Vec<i64> = routines.iter()
+        .flat_map(|routine| routine.bytes().chain(Some(b'\n')))
+        .map(i64::from)
+        .collect();
+
+    cpu.memory[0] = 2;
+    let outputs = cpu.run(&input);
+
+    outputs[outputs.len() - 1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expand<'a>(main: &[usize], functions: &[&[&'a str]]) -> Vec<&'a str> {
+        main.iter().flat_map(|&i| functions[i].iter().copied()).collect()
+    }
+
+    #[test]
+    fn test_scaffold_path() {
+        let image: Vec<Vec<char>> = [
+            "#######...#####",
+            "#.....#...#...#",
+            "#.....#...#...#",
+            "......#...#...#",
+            "......#...###.#",
+            "......#.....#.#",
+            "^########...#.#",
+            "......#.#...#.#",
+            "......#########",
+            "........#...#..",
+            "....#########..",
+            "....#...#......",
+            "....#...#......",
+            "....#...#......",
+            "....#####......",
+        ].iter().map(|row| row.chars().collect()).collect();
+
+        assert_eq!(
+            scaffold_path(&image).join(","),
+            "R,8,R,8,R,4,R,4,R,8,L,6,L,2,R,4,R,4,R,8,R,8,R,8,L,6,L,2"
+        );
+    }
+
+    #[test]
+    fn test_compress_example() {
+        let path: Vec<&str> = "R,8,R,8,R,4,R,4,R,8,L,6,L,2,R,4,R,4,R,8,R,8,R,8,L,6,L,2"
+            .split(',')
+            .collect();
+
+        let (main, functions) = compress(&path, 3, 20).unwrap();
+        assert!(functions.len() <= 3);
+        assert!(main.len() <= 10);
+        assert!(functions.iter().all(|f| routine_len(f) <= 20));
+        assert_eq!(expand(&main, &functions), path);
+    }
+
+    #[test]
+    fn test_compress_any_tokens() {
+        let tokens = ["x", "y", "x", "y", "z", "x", "y"];
+        let (main, functions) = compress(&tokens, 2, 7).unwrap();
+        assert_eq!(main, [0, 0, 1, 0]);
+        assert_eq!(functions, [&["x", "y"][..], &["z"][..]]);
+
+        assert_eq!(compress(&tokens, 1, 7), None);
+        assert_eq!(compress(&["too_long"], 3, 5), None);
+        assert_eq!(compress::<&str>(&[], 3, 20), Some((vec![], vec![])));
+    }
+}
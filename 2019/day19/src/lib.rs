@@ -0,0 +1,207 @@
+use std::collections::HashMap;
+
+use aoc::{Answer, Solution};
+use intcode::Computer;
+
+/// How far right of the origin, per row, to look for the beam in rows
+/// where it has not been seen yet.
+const MAX_SLOPE: i64 = 10;
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2019;
+    const DAY: u32 = 19;
+
+    type Input<'a> = Computer;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.parse().unwrap()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input.clone()).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input.clone()).into()
+    }
+}
+
+fn check(cpu: &Computer, x: i64, y: i64) -> bool {
+    let mut program = cpu.clone();
+    let outputs = program.run(&[x, y]);
+    outputs[0] == 1
+}
+
+/// A beam oracle with a cache, so every position is probed at most once.
+struct Beam<F> {
+    oracle: F,
+    cache: HashMap<(i64, i64), bool>,
+}
+
+impl<F: FnMut(i64, i64) -> bool> Beam<F> {
+    fn new(oracle: F) -> Self {
+        Self {
+            oracle,
+            cache: HashMap::new(),
+        }
+    }
+
+    fn probe(&mut self, x: i64, y: i64) -> bool {
+        let oracle = &mut self.oracle;
+        *self.cache.entry((x, y)).or_insert_with(|| oracle(x, y))
+    }
+}
+
+/// Follows the left and right edges of the beam row by row. Both edges only
+/// move right as the beam widens, so each row costs a few probes.
+struct EdgeTracker<F> {
+    beam: Beam<F>,
+    /// The first and last x of the beam in every row tracked so far,
+    /// or `None` for rows near the origin where the beam misses every cell.
+    rows: Vec<Option<(i64, i64)>>,
+    left: i64,
+    right: i64,
+}
+
+impl<F: FnMut(i64, i64) -> bool> EdgeTracker<F> {
+    fn new(oracle: F) -> Self {
+        Self {
+            beam: Beam::new(oracle),
+            rows: Vec::new(),
+            left: 0,
+            right: 0,
+        }
+    }
+
+    /// Return the edges of row `y`, tracking all rows up to it.
+    fn row(&mut self, y: i64) -> Option<(i64, i64)> {
+        while self.rows.len() as i64 <= y {
+            let row = self.next_row();
+            self.rows.push(row);
+        }
+        self.rows[y as usize]
+    }
+
+    fn next_row(&mut self) -> Option<(i64, i64)> {
+        let y = self.rows.len() as i64;
+
+        let mut x = self.left;
+        while !self.beam.probe(x, y) {
+            x += 1;
+            if x > MAX_SLOPE * (y + 1) {
+                return None;
+            }
+        }
+        self.left = x;
+
+        let mut x = self.right.max(self.left);
+        while self.beam.probe(x + 1, y) {
+            x += 1;
+        }
+        self.right = x;
+
+        Some((self.left, self.right))
+    }
+
+    /// Find the top left corner of the square of the given size closest to
+    /// the origin that fits entirely in the beam.
+    fn fit_square(&mut self, size: i64) -> (i64, i64) {
+        let mut bottom = size - 1;
+        loop {
+            if let (Some((left, _)), Some((_, right))) = (self.row(bottom), self.row(bottom - size + 1)) {
+                if right - left + 1 >= size {
+                    return (left, bottom - size + 1);
+                }
+            }
+            bottom += 1;
+        }
+    }
+}
+
+fn part1(cpu: Computer) -> usize {
+    let mut beam = Beam::new(|x, y| check(&cpu, x, y));
+
+    let mut count = 0;
+    for x in 0..50 {
+        for y in 0..50 {
+            if beam.probe(x, y) {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+fn part2(cpu: Computer) -> i64 {
+    let mut tracker = EdgeTracker::new(|x, y| check(&cpu, x, y));
+
+    let (x, y) = tracker.fit_square(100);
+    x * 10000 + y
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A beam between the lines 3x = 2y and 5x = 4y, with empty rows near the origin.
+    fn oracle(x: i64, y: i64) -> bool {
+        3 * x >= 2 * y && 5 * x <= 4 * y
+    }
+
+    fn brute_force_square(size: i64) -> (i64, i64) {
+        for y in 0.. {
+            for x in 0..=y {
+                let fits = (0..size).all(|dy| (0..size).all(|dx| oracle(x + dx, y + dy)));
+                if fits {
+                    return (x, y);
+                }
+            }
+        }
+        unreachable!()
+    }
+
+    #[test]
+    fn test_beam_cache() {
+        let mut calls = 0;
+        let mut beam = Beam::new(|x, y| {
+            calls += 1;
+            oracle(x, y)
+        });
+        assert!(beam.probe(7, 10));
+        assert!(beam.probe(7, 10));
+        assert!(!beam.probe(1, 10));
+        assert_eq!(beam.cache.len(), 2);
+        drop(beam);
+        assert_eq!(calls, 2);
+    }
+
+    #[test]
+    fn test_edges() {
+        let mut tracker = EdgeTracker::new(oracle);
+        for y in 0..200 {
+            let cells: Vec<i64> = (0..=y).filter(|&x| oracle(x, y)).collect();
+            let expected = cells.first().map(|&left| (left, *cells.last().unwrap()));
+            assert_eq!(tracker.row(y), expected, "row {}", y);
+        }
+    }
+
+    #[test]
+    fn test_fit_square() {
+        for &size in &[1, 2, 5, 10] {
+            let mut tracker = EdgeTracker::new(oracle);
+            assert_eq!(tracker.fit_square(size), brute_force_square(size), "size {}", size);
+        }
+    }
+
+    #[test]
+    fn test_probes_linear_in_rows() {
+        let mut tracker = EdgeTracker::new(oracle);
+        let (_, y) = tracker.fit_square(100);
+        let rows = tracker.rows.len();
+        assert!(rows as i64 >= y + 100);
+        let probes = tracker.beam.cache.len();
+        assert!(probes < 4 * rows, "{} probes for {} rows", probes, rows);
+    }
+}
@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 
-use crate::Tile;
+use grid::{Grid, Pos, ORTHOGONAL};
+
+use crate::{Map, Tile};
 
 enum LetterTileType {
     Start,
@@ -8,23 +10,16 @@ enum LetterTileType {
     Portal(char, char),
 }
 
-fn check_portal_start_end(x: usize, y: usize, map: &Vec<Vec<Tile>>) -> Option<LetterTileType> {
-    let (w, h) = (map[0].len(), map.len());
-    if x < 2 || y < 2 || x > w-2 || y > h-2 {
+fn check_portal_start_end(pos: Pos, map: &Map) -> Option<LetterTileType> {
+    if map[pos] != Tile::Floor {
         return None;
     }
 
-    if map[y][x] != Tile::Floor {
-        return None;
-    }
-
-    let (xi, yi) = (x as i32, y as i32);
-
-    for &(dx, dy) in [(-1, 0), (1, 0), (0, -1), (0, 1)].iter() {
-        let (x1, y1) = ((xi + dx) as usize, (yi + dy) as usize);
-        let (x2, y2) = ((xi + 2*dx) as usize, (yi + 2*dy) as usize);
+    for &(dx, dy) in ORTHOGONAL.iter() {
+        let letters = (map.offset(pos, (dx, dy)), map.offset(pos, (2 * dx, 2 * dy)));
+        let (Some(p1), Some(p2)) = letters else { continue };
 
-        match (map[y1][x1], map[y2][x2]) {
+        match (map[p1], map[p2]) {
             (Tile::Letter('A'), Tile::Letter('A')) => return Some(LetterTileType::Start),
             (Tile::Letter('Z'), Tile::Letter('Z')) => return Some(LetterTileType::End),
             (Tile::Letter(a), Tile::Letter(b)) => {
@@ -41,47 +36,40 @@ fn check_portal_start_end(x: usize, y: usize, map: &Vec<Vec<Tile>>) -> Option<Le
     None
 }
 
-pub(crate) fn parse_input(input: &str) -> (Vec<Vec<Tile>>, (usize, usize), (usize, usize)) {
-    let mut map: Vec<Vec<Tile>> = input.lines().map(|line| {
-        line.chars().map(|c| match c {
-            ' ' => Tile::Empty,
-            '.' => Tile::Floor,
-            '#' => Tile::Wall,
-            _ if c.is_alphabetic() => Tile::Letter(c),
-            _ => Tile::Empty,
-        }).collect()
-    }).collect();
+pub(crate) fn parse_input(input: &str) -> (Map, Pos, Pos) {
+    let mut map = Grid::parse(input, |c| Some(match c {
+        '.' => Tile::Floor,
+        '#' => Tile::Wall,
+        _ if c.is_alphabetic() => Tile::Letter(c),
+        _ => Tile::Empty,
+    })).unwrap();
 
     let mut start = (0, 0);
     let mut end = (0, 0);
 
     let mut portals = HashMap::new();
 
-    let h = map.len();
-    let w = map[0].len();
-    for y in 0..h {
-        for x in 0..w {
-            match check_portal_start_end(x, y, &map) {
-                Some(LetterTileType::Start) => {
-                    map[y][x] = Tile::Start;
-                    start = (x, y);
-                }
-                Some(LetterTileType::End) => {
-                    map[y][x] = Tile::End;
-                    end = (x, y);
-                },
-                Some(LetterTileType::Portal(a, b)) => {
-                    if let Some((other_x, other_y)) = portals.remove(&(a, b)) {
-                        map[y][x] = Tile::Portal(other_x, other_y);
-                        map[other_y][other_x] = Tile::Portal(x, y);
-                    } else {
-                        portals.insert((a, b), (x, y));
-                    }
+    for pos in map.positions() {
+        match check_portal_start_end(pos, &map) {
+            Some(LetterTileType::Start) => {
+                map[pos] = Tile::Start;
+                start = pos;
+            }
+            Some(LetterTileType::End) => {
+                map[pos] = Tile::End;
+                end = pos;
+            },
+            Some(LetterTileType::Portal(a, b)) => {
+                if let Some(other) = portals.remove(&(a, b)) {
+                    map[pos] = Tile::Portal(other);
+                    map[other] = Tile::Portal(pos);
+                } else {
+                    portals.insert((a, b), pos);
                 }
-                None => {}
             }
+            None => {}
         }
     }
 
     (map, start, end)
-}
\ No newline at end of file
+}
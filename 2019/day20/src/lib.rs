@@ -0,0 +1,123 @@
+use std::collections::HashSet;
+
+use aoc::{Answer, Solution};
+use grid::{Grid, ORTHOGONAL};
+
+mod input;
+
+use input::parse_input;
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2019;
+    const DAY: u32 = 20;
+
+    type Input<'a> = (Map, Point, Point);
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        let (map, start, end) = parse_input(input);
+        let start = (start.0, start.1, 0);
+        let end = (end.0, end.1, 0);
+        (map, start, end)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(&input.0, input.1, input.2).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(&input.0, input.1, input.2).into()
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Tile {
+    Empty,
+    Floor,
+    Wall,
+    Start,
+    End,
+    Portal(grid::Pos),
+
+    Letter(char),
+}
+
+/// Print the map for debugging, marking the cells on the route.
+#[allow(dead_code)]
+fn print_map(map: &Map, route: Option<&HashSet<grid::Pos>>) {
+    let map = Grid::from_fn(map.width(), map.height(), |pos| (pos, map[pos]));
+    println!("{}", map.render(|&(pos, tile)| {
+        if route.is_some_and(|route| route.contains(&pos)) {
+            return '█';
+        }
+        match tile {
+            Tile::Empty => ' ',
+            Tile::Floor => ' ',
+            Tile::Wall => '▒',
+            Tile::Start => '$',
+            Tile::End => '%',
+            Tile::Portal(_) => '@',
+            Tile::Letter(c) => c,
+        }
+    }));
+}
+
+type Point = (usize, usize, usize);
+
+type Map = Grid<Tile>;
+
+fn adjacent_cells(pos: Point, map: &Map, recurse: bool) -> Vec<Point> {
+    let (w, h) = (map.width(), map.height());
+    let (x, y, z) = pos;
+
+    let mut adj = Vec::new();
+    for (cx, cy) in map.neighbors((x, y), &ORTHOGONAL) {
+        match map[(cx, cy)] {
+            Tile::Floor | Tile::Portal(_)
+                | Tile::Start | Tile::End => adj.push((cx, cy, z)),
+            Tile::Letter(_) => {
+                if let Tile::Portal((target_x, target_y)) = map[(x, y)] {
+                    if !recurse {
+                        adj.push((target_x, target_y, z));
+                        continue;
+                    }
+
+                    // At z=0, only the inner portals work
+                    let is_outer = x == 2 || y == 2 || x == w-3 || y == h-3;
+                    if z != 0 {
+                        let target_z = if is_outer { z - 1 } else { z + 1 };
+                        adj.push((target_x, target_y, target_z));
+                    } else if !is_outer {
+                        adj.push((target_x, target_y, z + 1));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    adj
+}
+
+fn shortest_path(map: &Map, start: Point, end: Point, recurse: bool) -> Option<usize> {
+    search::bfs(start, |&pos| adjacent_cells(pos, map, recurse), |&pos| pos == end)
+        .map(|path| path.cost)
+}
+
+fn part1(map: &Map, start: Point, end: Point) -> usize {
+    shortest_path(map, start, end, false).unwrap()
+}
+
+fn part2(map: &Map, start: Point, end: Point) -> usize {
+    shortest_path(map, start, end, true).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::examples! {
+        Solver;
+        small: include_str!("../example") => part1 = 23, part2 = 26;
+    }
+}
@@ -0,0 +1,52 @@
+//! Statically check Intcode programs for mistakes that would make the
+//! interpreter panic at runtime.
+//!
+//! Usage: `intcode-lint <program>...`
+
+use std::env;
+use std::fs;
+use std::process;
+
+use intcode::lint::lint;
+
+fn parse_program(source: &str) -> Result<Vec<i64>, String> {
+    source
+        .split(',')
+        .enumerate()
+        .map(|(address, n)| {
+            n.trim()
+                .parse()
+                .map_err(|e| format!("address {}: cannot parse {:?}: {}", address, n.trim(), e))
+        })
+        .collect()
+}
+
+fn main() {
+    let paths: Vec<String> = env::args().skip(1).collect();
+    if paths.is_empty() {
+        eprintln!("usage: intcode-lint <program>...");
+        process::exit(2);
+    }
+
+    let mut problems = 0;
+    for path in &paths {
+        let program = match fs::read_to_string(path).map_err(|e| e.to_string()).and_then(|s| parse_program(&s)) {
+            Ok(program) => program,
+            Err(e) => {
+                eprintln!("{}: error: {}", path, e);
+                process::exit(2);
+            }
+        };
+
+        for diagnostic in lint(&program) {
+            println!("{}:{}: {}", path, diagnostic.address, diagnostic.lint);
+            println!("    help: {}", diagnostic.lint.suggestion());
+            problems += 1;
+        }
+    }
+
+    if problems > 0 {
+        eprintln!("{} problem(s) found", problems);
+        process::exit(1);
+    }
+}
@@ -2,9 +2,13 @@
 //! Intcode interpreter for Advent of Code 2019.
 
 use std::convert::{From, TryInto};
+use std::num::ParseIntError;
 use std::path::Path;
+use std::str::FromStr;
 use std::fs;
 
+pub mod lint;
+
 const MAX_PARAMETERS: usize = 3;
 
 #[repr(u8)]
@@ -61,6 +65,29 @@ impl From<&[i64]> for Computer {
     }
 }
 
+impl FromStr for Computer {
+    type Err = ParseIntError;
+
+    /// Parse a comma separated program.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let memory = s
+            .split(',')
+            .map(|n| n.trim().parse())
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            memory,
+            pc: 0,
+            relative_base: 0,
+        })
+    }
+}
+
+impl Default for Computer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Computer {
     /// Construct a new empty computer.
     pub fn new() -> Self {
@@ -73,16 +100,7 @@ impl Computer {
 
     /// Construct a computer from a file.
     pub fn from_file<P: AsRef<Path>>(path: P) -> Self {
-        let memory = fs::read_to_string(path)
-            .unwrap()
-            .split(',')
-            .map(|n| n.trim().parse().unwrap())
-            .collect();
-        Self {
-            memory,
-            pc: 0,
-            relative_base: 0,
-        }
+        fs::read_to_string(path).unwrap().parse().unwrap()
     }
 
     /// Print a memory dump to stderr.
@@ -120,8 +138,8 @@ impl Computer {
         let opcode = instruction % 100;
         let mut modes_mask = instruction / 100;
         let mut modes = [ParameterMode::Position; MAX_PARAMETERS];
-        for i in 0..MAX_PARAMETERS {
-            modes[i] = ParameterMode::new(modes_mask % 10);
+        for mode in modes.iter_mut() {
+            *mode = ParameterMode::new(modes_mask % 10);
             modes_mask /= 10;
         }
         (opcode, modes)
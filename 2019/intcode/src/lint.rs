@@ -0,0 +1,280 @@
+//! Static checks for Intcode programs.
+//!
+//! The linter walks the program from address 0, following every control flow
+//! path it can resolve without running the program, and reports instructions
+//! that would make the interpreter panic. Both branches of a jump are followed
+//! unless its condition is an immediate value, jumps to computed (position or
+//! relative mode) targets are not followed, and self-modifying code is
+//! analyzed as it is written, so the results are a best effort.
+
+use std::collections::{BTreeSet, HashSet};
+use std::fmt;
+
+use crate::MAX_PARAMETERS;
+
+/// A problem found by the linter.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Lint {
+    /// The opcode is not one of 1-9 or 99.
+    InvalidOpcode(i64),
+    /// A parameter mode digit is not 0, 1 or 2.
+    InvalidMode { param: usize, mode: i64 },
+    /// A destination parameter is in immediate mode.
+    ImmediateDestination { param: usize },
+    /// A position mode parameter refers to a negative address.
+    NegativeAddress { param: usize, address: i64 },
+    /// A jump with a constant target jumps outside of the program.
+    JumpOutOfRange { target: i64 },
+    /// The instruction's parameters extend past the end of the program.
+    TruncatedInstruction { expected: usize, available: usize },
+    /// Execution continues past the last address without halting.
+    RunsOffEnd,
+    /// Every path from address 0 loops forever.
+    NoHalt,
+}
+
+impl Lint {
+    /// A suggested fix for the problem.
+    pub fn suggestion(&self) -> String {
+        match self {
+            Lint::InvalidOpcode(_) => "use one of the opcodes 1-9 or 99".to_string(),
+            Lint::InvalidMode { param, .. } => format!(
+                "change the mode digit of parameter {} to 0 (position), 1 (immediate) or 2 (relative)",
+                param + 1
+            ),
+            Lint::ImmediateDestination { param } => format!(
+                "change the mode digit of parameter {} to 0 (position) or 2 (relative)",
+                param + 1
+            ),
+            Lint::NegativeAddress { param, .. } => format!(
+                "use relative mode for parameter {} or make the address non-negative",
+                param + 1
+            ),
+            Lint::JumpOutOfRange { .. } => "jump to an address inside the program".to_string(),
+            Lint::TruncatedInstruction { expected, available } => format!(
+                "add the {} missing parameter(s)",
+                expected - available
+            ),
+            Lint::RunsOffEnd | Lint::NoHalt => "add a halt instruction (99)".to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Lint::InvalidOpcode(opcode) => write!(f, "invalid opcode {}", opcode),
+            Lint::InvalidMode { param, mode } => {
+                write!(f, "invalid mode {} for parameter {}", mode, param + 1)
+            }
+            Lint::ImmediateDestination { param } => {
+                write!(f, "destination parameter {} is in immediate mode", param + 1)
+            }
+            Lint::NegativeAddress { param, address } => {
+                write!(f, "parameter {} refers to negative address {}", param + 1, address)
+            }
+            Lint::JumpOutOfRange { target } => write!(f, "jump target {} is out of range", target),
+            Lint::TruncatedInstruction { expected, available } => write!(
+                f,
+                "instruction expects {} parameter(s) but only {} remain",
+                expected, available
+            ),
+            Lint::RunsOffEnd => write!(f, "execution runs past the end of the program"),
+            Lint::NoHalt => write!(f, "no halt instruction is reachable, the program loops forever"),
+        }
+    }
+}
+
+/// A lint together with the address of the instruction it applies to.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Diagnostic {
+    pub address: usize,
+    pub lint: Lint,
+}
+
+/// Return the number of parameters of an opcode and the index of its
+/// destination parameter, if any.
+fn signature(opcode: i64) -> Option<(usize, Option<usize>)> {
+    match opcode {
+        1 | 2 | 7 | 8 => Some((3, Some(2))),
+        3 => Some((1, Some(0))),
+        4 | 9 => Some((1, None)),
+        5 | 6 => Some((2, None)),
+        99 => Some((0, None)),
+        _ => None,
+    }
+}
+
+/// Statically check a program and return the problems found, ordered by address.
+pub fn lint(program: &[i64]) -> Vec<Diagnostic> {
+    let mut diagnostics = BTreeSet::new();
+    let mut visited = HashSet::new();
+    let mut queue = vec![0];
+    let mut terminates = false;
+    let mut complete = true;
+
+    let mut report = |address, lint| {
+        diagnostics.insert(Diagnostic { address, lint });
+    };
+
+    while let Some(pc) = queue.pop() {
+        if !visited.insert(pc) {
+            continue;
+        }
+
+        let instruction = match program.get(pc) {
+            Some(&instruction) => instruction,
+            None => {
+                report(pc, Lint::RunsOffEnd);
+                terminates = true;
+                continue;
+            }
+        };
+
+        let opcode = instruction % 100;
+        let (count, destination) = match signature(opcode) {
+            Some(signature) => signature,
+            None => {
+                report(pc, Lint::InvalidOpcode(opcode));
+                terminates = true;
+                continue;
+            }
+        };
+
+        // the interpreter decodes all mode digits, not just the ones in use
+        let mut modes = [0; MAX_PARAMETERS];
+        let mut modes_mask = instruction / 100;
+        for (param, mode) in modes.iter_mut().enumerate() {
+            *mode = modes_mask % 10;
+            modes_mask /= 10;
+            if !(0..=2).contains(mode) {
+                report(pc, Lint::InvalidMode { param, mode: *mode });
+            }
+        }
+
+        if opcode == 99 {
+            terminates = true;
+            continue;
+        }
+
+        let available = program.len() - pc - 1;
+        if available < count {
+            report(pc, Lint::TruncatedInstruction { expected: count, available });
+            terminates = true;
+            continue;
+        }
+        let params = &program[pc + 1..=pc + count];
+
+        if let Some(param) = destination {
+            if modes[param] == 1 {
+                report(pc, Lint::ImmediateDestination { param });
+            }
+        }
+        for (param, &value) in params.iter().enumerate() {
+            if modes[param] == 0 && value < 0 {
+                report(pc, Lint::NegativeAddress { param, address: value });
+            }
+        }
+
+        let next = pc + 1 + count;
+        if opcode == 5 || opcode == 6 {
+            // a constant condition decides the branch statically
+            let (may_jump, may_continue) = if modes[0] == 1 {
+                let jumps = (params[0] != 0) == (opcode == 5);
+                (jumps, !jumps)
+            } else {
+                (true, true)
+            };
+
+            if may_jump {
+                if modes[1] == 1 {
+                    let target = params[1];
+                    if target < 0 || target as usize >= program.len() {
+                        report(pc, Lint::JumpOutOfRange { target });
+                        terminates = true;
+                    } else {
+                        queue.push(target as usize);
+                    }
+                } else {
+                    complete = false;
+                }
+            }
+            if may_continue {
+                queue.push(next);
+            }
+        } else {
+            queue.push(next);
+        }
+    }
+
+    if !terminates && complete {
+        report(0, Lint::NoHalt);
+    }
+
+    diagnostics.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lints(program: &[i64]) -> Vec<(usize, Lint)> {
+        lint(program).into_iter().map(|d| (d.address, d.lint)).collect()
+    }
+
+    #[test]
+    fn test_clean_program() {
+        assert_eq!(lints(&[3,9,8,9,10,9,4,9,99,-1,8]), []);
+        assert_eq!(lints(&[3,3,1105,-1,9,1101,0,0,12,4,12,99,1]), []);
+        assert_eq!(lints(&[109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99]), []);
+    }
+
+    #[test]
+    fn test_invalid_opcode() {
+        assert_eq!(lints(&[1101,1,1,0,42,99]), [(4, Lint::InvalidOpcode(42))]);
+    }
+
+    #[test]
+    fn test_invalid_mode() {
+        assert_eq!(
+            lints(&[1301,1,1,0,99]),
+            [(0, Lint::InvalidMode { param: 0, mode: 3 })]
+        );
+    }
+
+    #[test]
+    fn test_immediate_destination() {
+        assert_eq!(
+            lints(&[11101,1,1,0,103,0,99]),
+            [
+                (0, Lint::ImmediateDestination { param: 2 }),
+                (4, Lint::ImmediateDestination { param: 0 }),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_jump_out_of_range() {
+        assert_eq!(lints(&[1105,1,50,99]), [(0, Lint::JumpOutOfRange { target: 50 })]);
+        assert_eq!(lints(&[1106,1,-1,99]), []);
+    }
+
+    #[test]
+    fn test_missing_halt() {
+        assert_eq!(lints(&[1101,1,1,0]), [(4, Lint::RunsOffEnd)]);
+        assert_eq!(lints(&[1105,1,0]), [(0, Lint::NoHalt)]);
+    }
+
+    #[test]
+    fn test_truncated_instruction() {
+        assert_eq!(
+            lints(&[1101,1]),
+            [(0, Lint::TruncatedInstruction { expected: 3, available: 1 })]
+        );
+    }
+
+    #[test]
+    fn test_unreachable_code_is_ignored() {
+        assert_eq!(lints(&[99,42,11101,0,0,0]), []);
+    }
+}
@@ -0,0 +1,61 @@
+use aoc::{Answer, Solution};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2020;
+    const DAY: u32 = 1;
+
+    type Input<'a> = Vec<i32>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.lines().map(|n| n.parse().unwrap()).collect()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+fn part1(input: &[i32]) -> i32 {
+    for (i, &a) in input.iter().enumerate() {
+        for &b in input[i..].iter() {
+            if a + b == 2020 {
+                return a * b;
+            }
+        }
+    }
+    unreachable!()
+}
+
+fn part2(input: &[i32]) -> i32 {
+    for (i, &a) in input.iter().enumerate() {
+        for (j, &b) in input[i..].iter().enumerate() {
+            for &c in input[(i+j)..].iter() {
+                if a + b + c == 2020 {
+                    return a * b * c;
+                }
+            }
+        }
+    }
+    unreachable!()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::examples! {
+        Solver;
+        expenses: "1721
+979
+366
+299
+675
+1456" => part1 = 514579, part2 = 241861950;
+    }
+}
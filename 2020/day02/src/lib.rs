@@ -0,0 +1,59 @@
+use aoc::{Answer, Solution};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2020;
+    const DAY: u32 = 2;
+
+    type Input<'a> = Vec<(usize, usize, char, &'a str)>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+            .lines()
+            .map(|l| {
+                let mut parts = l.split(' ');
+                let mut bounds = parts.next().unwrap().split('-')
+                    .map(|n| n.parse().unwrap());
+                let x = bounds.next().unwrap();
+                let y = bounds.next().unwrap();
+                let c = parts.next().unwrap()[..1].parse().unwrap();
+                let pass = parts.next().unwrap();
+                (x, y, c, pass)
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+fn part1(input: &[(usize, usize, char, &str)]) -> usize {
+    input.iter().filter(|(x, y, c, pass)| {
+        (x..=y).contains(&&pass.chars().filter(|pc| pc == c).count())
+    }).count()
+}
+
+fn part2(input: &[(usize, usize, char, &str)]) -> usize {
+    input.iter().filter(|(x, y, c, pass)| {
+        let chars: Vec<char> = pass.chars().collect();
+        (&chars[x-1] == c) ^ (&chars[y-1] == c)
+    }).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::examples! {
+        Solver;
+        passwords: "1-3 a: abcde
+1-3 b: cdefg
+2-9 c: ccccccccc" => part1 = 2, part2 = 1;
+    }
+}
@@ -0,0 +1,77 @@
+use aoc::{Answer, Solution};
+use grid::Grid;
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2020;
+    const DAY: u32 = 3;
+
+    type Input<'a> = Map;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse(input).unwrap()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Tile {
+    Open,
+    Tree,
+}
+
+type Map = Grid<Tile>;
+
+fn parse(s: &str) -> Result<Map, grid::ParseError> {
+    Grid::parse(s, |c| match c {
+        '.' => Some(Tile::Open),
+        '#' => Some(Tile::Tree),
+        _ => None,
+    })
+}
+
+fn count_trees_by(map: &Map, dx: usize, dy: usize) -> usize {
+    (0..map.height())
+        .step_by(dy)
+        .zip((0..).step_by(dx))
+        .filter(|&(y, x)| *map.get_wrapping((x, y)) == Tile::Tree)
+        .count()
+}
+
+fn part1(input: &Map) -> usize {
+    count_trees_by(input, 3, 1)
+}
+
+fn part2(input: &Map) -> usize {
+    [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)].iter()
+        .map(|&(dx, dy)| count_trees_by(input, dx, dy))
+        .product()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::examples! {
+        Solver;
+        slope: "..##.......
+#...#...#..
+.#....#..#.
+..#.#...#.#
+.#...##..#.
+..#.##.....
+.#.#.#....#
+.#........#
+#.##...#...
+#...##....#
+.#..#...#.#" => part1 = 7, part2 = 336;
+    }
+}
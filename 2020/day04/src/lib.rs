@@ -0,0 +1,153 @@
+use std::collections::HashMap;
+
+use aoc::{Answer, Solution};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2020;
+    const DAY: u32 = 4;
+
+    type Input<'a> = Vec<HashMap<&'a str, &'a str>>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        scan::blocks(input)
+            .map(|passport| {
+                let fields = passport.parse(|s| scan::words(s, |field| scan::scan!(field, "{}:{}" => &str, &str)));
+                scan::or_panic(fields).into_iter().collect()
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+fn has_required_fields(passport: &HashMap<&str, &str>) -> bool {
+    const REQUIRED_FIELDS: [&str; 7] = ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
+    REQUIRED_FIELDS.iter().all(|&field| passport.contains_key(field))
+}
+
+fn part1(input: &[HashMap<&str, &str>]) -> usize {
+    input.iter()
+        .filter(|&passport| has_required_fields(passport))
+        .count()
+}
+
+fn validate_fields(passport: &HashMap<&str, &str>) -> bool {
+    if !has_required_fields(passport) {
+        return false;
+    }
+
+    // check byr, iyr, eyr
+
+    const YEAR_FIELDS: [(&str, i32, i32); 3] = [
+        ("byr", 1920, 2002),
+        ("iyr", 2010, 2020),
+        ("eyr", 2020, 2030),
+    ];
+    let invalid = YEAR_FIELDS.iter().any(|&(field, x, y)| {
+        passport.get(field).unwrap().parse()
+            .map(|value: i32| value < x || value > y)
+            .unwrap_or(false)
+    });
+    if invalid {
+        return false;
+    }
+
+    // check hgt
+
+    let &hgt = passport.get("hgt").unwrap();
+    let suffix = hgt.get(hgt.len()-2..);
+    let value = suffix.and_then(|s| hgt.trim_end_matches(s).parse::<i32>().ok());
+    match (suffix, value) {
+        (Some("cm"), Some(n)) => if !(150..=193).contains(&n) { return false; }
+        (Some("in"), Some(n)) => if !(59..=76).contains(&n) { return false; }
+        _ => return false,
+    }
+
+    // check hcl
+
+    let &hcl = passport.get("hcl").unwrap();
+    if &hcl[..1] != "#" || hcl.len() != 7 || i64::from_str_radix(&hcl[1..], 16).is_err() {
+        return false;
+    }
+
+    // check ecl
+
+    const VALID_COLORS: [&str; 7] = ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"];
+    let invalid = VALID_COLORS.iter().all(|c| passport.get("ecl").unwrap() != c);
+    if invalid {
+        return false;
+    }
+
+    // check pid
+
+    let &pid = passport.get("pid").unwrap();
+    if pid.len() != 9 || pid.parse::<i64>().is_err() {
+        return false;
+    }
+
+    true
+}
+
+fn part2(input: &[HashMap<&str, &str>]) -> usize {
+    input.iter()
+        .filter(|&passport| {
+            //println!("{:?}", passport);
+            validate_fields(passport)
+        })
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::examples! {
+        Solver;
+        required_fields: "ecl:gry pid:860033327 eyr:2020 hcl:#fffffd
+byr:1937 iyr:2017 cid:147 hgt:183cm
+
+iyr:2013 ecl:amb cid:350 eyr:2023 pid:028048884
+hcl:#cfa07d byr:1929
+
+hcl:#ae17e1 iyr:2013
+eyr:2024
+ecl:brn pid:760753108 byr:1931
+hgt:179cm
+
+hcl:#cfa07d eyr:2025 pid:166559648
+iyr:2011 ecl:brn hgt:59in" => part1 = 2;
+        invalid: "eyr:1972 cid:100
+hcl:#18171d ecl:amb hgt:170 pid:186cm iyr:2018 byr:1926
+
+iyr:2019
+hcl:#602927 eyr:1967 hgt:170cm
+ecl:grn pid:012533040 byr:1946
+
+hcl:dab227 iyr:2012
+ecl:brn hgt:182cm pid:021572410 eyr:2020 byr:1992 cid:277
+
+hgt:59cm ecl:zzz
+eyr:2038 hcl:74454a iyr:2023
+pid:3556412378 byr:2007" => part2 = 0;
+        valid: "pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980
+hcl:#623a2f
+
+eyr:2029 ecl:blu cid:129 byr:1989
+iyr:2014 pid:896056539 hcl:#a97842 hgt:165cm
+
+hcl:#888785
+hgt:164cm byr:2001 iyr:2015 cid:88
+pid:545766238 ecl:hzl
+eyr:2022
+
+iyr:2010 hgt:158cm hcl:#b6652a ecl:blu byr:1944 eyr:2021 pid:093154719" => part2 = 4;
+    }
+}
@@ -0,0 +1,68 @@
+use aoc::{Answer, Solution};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2020;
+    const DAY: u32 = 5;
+
+    type Input<'a> = Vec<(&'a str, &'a str)>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.lines()
+            .map(|l| (&l[..7], &l[7..]))
+            .collect()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+fn bsp(s: &str, n_lo: i32, n_hi: i32) -> i32 {
+    s.chars().fold((n_lo, n_hi), |(lo, hi), c| {
+        if c == 'F' || c == 'L' {
+            (lo, (lo+hi)/2)
+        } else {
+            ((lo+hi)/2+1, hi)
+        }
+    }).0
+}
+
+fn iter_ids<'a>(input: &'a [(&str, &str)]) -> impl Iterator<Item=i32> + 'a {
+    input.iter()
+        .map(|&(row, col)| (bsp(row, 0, 127), bsp(col, 0, 7)))
+        .map(|(row, col)| row*8 + col)
+}
+
+fn part1(input: &[(&str, &str)]) -> i32 {
+    iter_ids(input)
+        .max()
+        .unwrap()
+}
+
+fn part2(input: &[(&str, &str)]) -> i32 {
+    let mut ids: Vec<i32> = iter_ids(input).collect();
+    ids.sort();
+    ids.windows(2)
+        .find(|&w| w[1] - w[0] == 2)
+        .map(|w| w[0] + 1)
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::examples! {
+        Solver;
+        single: "FBFBBFFRLR" => part1 = 357;
+        highest: "BFFFBBFRRR
+FFFBBBFRRR
+BBFFBBFRLL" => part1 = 820;
+    }
+}
@@ -0,0 +1,76 @@
+use std::collections::HashSet;
+
+use aoc::{Answer, Solution};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2020;
+    const DAY: u32 = 6;
+
+    type Input<'a> = Vec<Vec<&'a str>>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+            .split("\n\n")
+            .map(|group| group.lines().collect())
+            .collect()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+fn part1(input: &[Vec<&str>]) -> usize {
+    input.iter()
+        .map(|group| {
+            group.iter().flat_map(|answers| answers.chars()).collect::<HashSet<char>>()
+        })
+        .map(|answers| answers.len())
+        .sum()
+}
+
+fn part2(input: &[Vec<&str>]) -> usize {
+    input.iter()
+        .map(|group| {
+            let mut answers: Vec<HashSet<char>> = group.iter()
+                .map(|answers| answers.chars().collect::<HashSet<char>>())
+                .collect();
+            let last = answers.pop().unwrap();
+            answers.into_iter()
+                .fold(last, |acc, curr| {
+                    acc.intersection(&curr).copied().collect()
+                })
+                .len()
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::examples! {
+        Solver;
+        groups: "abc
+
+a
+b
+c
+
+ab
+ac
+
+a
+a
+a
+a
+
+b" => part1 = 11, part2 = 6;
+    }
+}
@@ -0,0 +1,90 @@
+use std::collections::HashMap;
+
+use aoc::{Answer, Solution};
+
+type BagMap<'a> = HashMap<&'a str, Vec<(i32, &'a str)>>;
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2020;
+    const DAY: u32 = 7;
+
+    type Input<'a> = BagMap<'a>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.lines()
+            .map(|l| {
+                let mut parts = l.split(" contain ");
+                let container = parts.next().unwrap().trim_end_matches(" bags");
+                let contents = parts.next().unwrap();
+                let contents: Vec<_> = contents[..contents.len()-1].split(", ")
+                    .filter_map(|bag| {
+                        if bag == "no other bags" {
+                            return None;
+                        }
+                        let bag = bag.trim_end_matches('s').trim_end_matches(" bag");
+                        let num_idx = bag.find(' ').unwrap();
+                        let num = bag[..num_idx].parse().unwrap();
+                        Some((num, &bag[num_idx+1..]))
+                    })
+                    .collect();
+                (container, contents)
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+fn contains_gold(bag: &str, bags: &BagMap) -> bool {
+    let contents = bags.get(bag).unwrap();
+    contents.iter().any(|&(_, b)| b == "shiny gold")
+    || contents.iter().any(|&(_, b)| contains_gold(b, bags))
+}
+
+fn part1(input: &BagMap) -> usize {
+    input.iter().filter(|(&b, _)| contains_gold(b, input)).count()
+}
+
+fn count_contained_bags(bag: &str, bags: &BagMap) -> i32 {
+    bags.get(bag).unwrap()
+        .iter()
+        .map(|&(n, b)| n + n * count_contained_bags(b, bags))
+        .sum()
+}
+
+fn part2(input: &BagMap) -> i32 {
+    count_contained_bags("shiny gold", input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::examples! {
+        Solver;
+        rules: "light red bags contain 1 bright white bag, 2 muted yellow bags.
+dark orange bags contain 3 bright white bags, 4 muted yellow bags.
+bright white bags contain 1 shiny gold bag.
+muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
+shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
+dark olive bags contain 3 faded blue bags, 4 dotted black bags.
+vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
+faded blue bags contain no other bags.
+dotted black bags contain no other bags." => part1 = 4, part2 = 32;
+        nested: "shiny gold bags contain 2 dark red bags.
+dark red bags contain 2 dark orange bags.
+dark orange bags contain 2 dark yellow bags.
+dark yellow bags contain 2 dark green bags.
+dark green bags contain 2 dark blue bags.
+dark blue bags contain 2 dark violet bags.
+dark violet bags contain no other bags." => part2 = 126;
+    }
+}
@@ -0,0 +1,115 @@
+use std::collections::HashSet;
+
+use aoc::{Answer, Solution};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2020;
+    const DAY: u32 = 8;
+
+    type Input<'a> = Vec<Instr>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse_program(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum Instr {
+    Acc(i32),
+    Jmp(isize),
+    Nop(isize),
+}
+
+fn parse_program(s: &str) -> Vec<Instr> {
+    s.lines()
+        .map(|l| {
+
+            match &l[..3] {
+                "acc" => Instr::Acc(l[4..].parse().unwrap()),
+                "jmp" => Instr::Jmp(l[4..].parse().unwrap()),
+                "nop" => Instr::Nop(l[4..].parse().unwrap()),
+                _ => unreachable!(),
+            }
+        })
+        .collect()
+}
+
+enum RunResult {
+    Loop(i32),
+    Terminate(i32),
+}
+
+fn try_program(program: &[Instr]) -> RunResult {
+    let mut acc = 0;
+    let mut pc: isize = 0;
+    let mut executed = HashSet::new();
+    loop {
+        if (pc as usize) >= program.len() {
+            return RunResult::Terminate(acc);
+        }
+        if !executed.insert(pc) {
+            return RunResult::Loop(acc);
+        }
+
+        match program[pc as usize] {
+            Instr::Acc(n) => acc += n,
+            Instr::Jmp(n) => {
+                pc += n;
+                continue;
+            }
+            Instr::Nop(_) => {}
+        }
+
+        pc += 1;
+    }
+}
+
+fn part1(program: &[Instr]) -> i32 {
+    match try_program(program) {
+        RunResult::Loop(acc) => acc,
+        RunResult::Terminate(_) => panic!("The program terminated"),
+    }
+}
+
+fn part2(program: &[Instr]) -> i32 {
+    for (idx, i) in program.iter().enumerate() {
+        let mut program = program.to_owned();
+        match i {
+            Instr::Acc(_) => continue,
+            Instr::Jmp(n) => program[idx] = Instr::Nop(*n),
+            Instr::Nop(n) => program[idx] = Instr::Jmp(*n),
+        }
+        if let RunResult::Terminate(acc) = try_program(&program) {
+            return acc;
+        }
+    }
+    unreachable!()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::examples! {
+        Solver;
+        boot_code: "nop +0
+acc +1
+jmp +4
+acc +3
+jmp -3
+acc -99
+acc +1
+jmp -4
+acc +6" => part1 = 5, part2 = 8;
+    }
+}
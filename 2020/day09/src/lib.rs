@@ -0,0 +1,99 @@
+use aoc::{Answer, Solution};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2020;
+    const DAY: u32 = 9;
+
+    type Input<'a> = Vec<i64>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.lines().map(|l| l.parse().unwrap()).collect()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input, PREAMBLE).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input, PREAMBLE).into()
+    }
+}
+
+const PREAMBLE: usize = 25;
+
+fn find_breaking(input: &[i64], preamble: usize) -> i64 {
+    input.windows(preamble + 1)
+        .find(|&w| {
+            for (i, a) in w.iter().enumerate() {
+                for b in w[i..].iter() {
+                    if a + b == w[w.len() - 1] {
+                        return false;
+                    }
+                }
+            }
+            true
+        })
+        .map(|w| w[w.len() - 1])
+        .unwrap()
+}
+
+fn part1(input: &[i64], preamble: usize) -> i64 {
+    find_breaking(input, preamble)
+}
+
+fn min_max(w: &[i64]) -> (i64, i64) {
+    let i = w[0];
+    w[1..].iter().fold((i, i), |(min, max), &val| {
+        (min.min(val), max.max(val))
+    })
+}
+
+fn part2(input: &[i64], preamble: usize) -> i64 {
+    let n = find_breaking(input, preamble);
+    for len in 2..input.len() {
+        for w in input.windows(len) {
+            if w.iter().sum::<i64>() == n {
+                let (min, max) = min_max(w);
+                return min + max;
+            }
+        }
+    }
+    unreachable!()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+35
+20
+15
+25
+47
+40
+62
+55
+65
+95
+102
+117
+150
+182
+127
+219
+299
+277
+309
+576";
+
+    #[test]
+    fn test_example() {
+        let input = Solver::parse(EXAMPLE);
+
+        assert_eq!(part1(&input, 5), 127);
+        assert_eq!(part2(&input, 5), 62);
+    }
+}
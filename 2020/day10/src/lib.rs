@@ -0,0 +1,138 @@
+use std::collections::HashMap;
+
+use aoc::{Answer, Solution};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2020;
+    const DAY: u32 = 10;
+
+    type Input<'a> = Vec<i32>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        let mut ratings: Vec<i32> = input
+            .lines()
+            .map(|l| l.parse().unwrap())
+            .collect();
+
+        ratings.push(0);
+        ratings.sort();
+        ratings.push(ratings.last().unwrap() + 3);
+        ratings
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+fn part1(input: &[i32]) -> i32 {
+    let (d1, d3) = input.windows(2).map(|w| w[1] - w[0])
+        .fold((0, 0), |(d1, d3), d| {
+            match d {
+                1 => (d1 + 1, d3),
+                3 => (d1, d3 + 1),
+                _ => (d1, d3),
+            }
+        });
+    d1 * d3
+}
+
+/// Memoized recursive arrange function
+fn arrange(
+    mem: &mut HashMap<(usize, usize), u64>,
+    adapters: &mut [i32],
+    i: usize,
+    prev: usize
+) -> u64
+{
+    if let Some(&c) = mem.get(&(i, prev)) {
+        return c;
+    }
+
+    if i == adapters.len() - 1 {
+        return 1;
+    }
+
+    let mut count = arrange(mem, adapters, i + 1, i);
+
+    // Remove the current index if the gap between
+    // the next and the previous used index is not
+    // too big
+    let diff = adapters[i + 1] - adapters[prev];
+    if (1..=3).contains(&diff) {
+        let p = adapters[i];
+        adapters[i] = i32::MIN;
+        count += arrange(mem, adapters, i + 1, prev);
+        adapters[i] = p;
+    }
+
+    mem.insert((i, prev), count);
+
+    count
+}
+
+fn part2(input: &[i32]) -> u64 {
+    arrange(
+        &mut HashMap::new(),
+        &mut input.to_owned(),
+        1,
+        0
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::examples! {
+        Solver;
+        small: "16
+10
+15
+5
+1
+11
+7
+19
+6
+12
+4" => part1 = 35, part2 = 8;
+        large: "28
+33
+18
+42
+31
+14
+46
+20
+48
+47
+24
+23
+49
+45
+19
+38
+39
+11
+1
+32
+25
+35
+8
+17
+7
+9
+4
+2
+34
+10
+3" => part1 = 220, part2 = 19208;
+    }
+}
@@ -0,0 +1,83 @@
+use aoc::{Answer, Solution};
+use automaton::{Automaton, Boundary, Neighborhood};
+use grid::Grid;
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2020;
+    const DAY: u32 = 11;
+
+    type Input<'a> = Grid<Cell>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Cell {
+    Floor,
+    Empty,
+    Taken,
+}
+
+fn parse(s: &str) -> Grid<Cell> {
+    Grid::parse(s, |c| match c {
+        '.' => Some(Cell::Floor),
+        'L' => Some(Cell::Empty),
+        '#' => Some(Cell::Taken),
+        _ => None,
+    })
+    .unwrap()
+}
+
+/// Let people sit down and get up until nobody moves any more, and count
+/// the taken seats.
+fn simulate_until_stable(seats: &Grid<Cell>, neighborhood: Neighborhood<Cell>, min_taken: usize) -> usize {
+    let mut area = Automaton::new(seats.clone(), neighborhood, Boundary::Fixed);
+    area.run_until_stable(|cell, neighbors| {
+        let taken = neighbors.iter().filter(|&&n| n == Cell::Taken).count();
+        match cell {
+            Cell::Empty if taken == 0 => Cell::Taken,
+            Cell::Taken if taken >= min_taken => Cell::Empty,
+            cell => cell,
+        }
+    });
+    area.count(|&cell| cell == Cell::Taken)
+}
+
+fn part1(seats: &Grid<Cell>) -> usize {
+    simulate_until_stable(seats, Neighborhood::Moore, 4)
+}
+
+fn part2(seats: &Grid<Cell>) -> usize {
+    simulate_until_stable(seats, Neighborhood::LineOfSight(|&cell| cell == Cell::Floor), 5)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::examples! {
+        Solver;
+        seats: "L.LL.LL.LL
+LLLLLLL.LL
+L.L.L..L..
+LLLL.LL.LL
+L.LL.LL.LL
+L.LLLLL.LL
+..L.L.....
+LLLLLLLLLL
+L.LLLLLL.L
+L.LLLLL.LL" => part1 = 37, part2 = 26;
+    }
+}
@@ -0,0 +1,84 @@
+use aoc::{Answer, Solution};
+use geometry::{Dir4, Point2, Turtle};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2020;
+    const DAY: u32 = 12;
+
+    type Input<'a> = Vec<Move>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+enum Instr {
+    Move(Dir4),
+    Turn,
+    Forward,
+}
+
+pub struct Move(Instr, i64);
+
+fn parse(s: &str) -> Vec<Move> {
+    s.lines()
+        .map(|l| {
+            let n: i64 = l[1..].parse().unwrap();
+            match l.chars().next().unwrap() {
+                'L' => Move(Instr::Turn, -n / 90),
+                'R' => Move(Instr::Turn, n / 90),
+                'F' => Move(Instr::Forward, n),
+                c => Move(Instr::Move(Dir4::from_char(c).unwrap()), n),
+            }
+        })
+        .collect()
+}
+
+fn part1(input: &[Move]) -> i64 {
+    let mut ferry = Turtle::new(Point2::ORIGIN, Dir4::E);
+    for m in input.iter() {
+        match m.0 {
+            Instr::Move(dir) => ferry.pos += dir.step() * m.1,
+            Instr::Turn => ferry.turn(m.1),
+            Instr::Forward => ferry.forward(m.1),
+        }
+    }
+    ferry.pos.manhattan()
+}
+
+fn part2(input: &[Move]) -> i64 {
+    let mut ferry = Point2::ORIGIN;
+    let mut waypoint = Point2::new(10, -1);
+    for m in input.iter() {
+        match m.0 {
+            Instr::Move(dir) => waypoint += dir.step() * m.1,
+            Instr::Turn => waypoint = waypoint.rotate(m.1),
+            Instr::Forward => ferry += waypoint * m.1,
+        }
+    }
+    ferry.manhattan()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::examples! {
+        Solver;
+        navigation: "F10
+N3
+F7
+R90
+F11" => part1 = 25, part2 = 286;
+    }
+}
@@ -0,0 +1,83 @@
+use aoc::{Answer, Solution};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2020;
+    const DAY: u32 = 13;
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+fn parse_1(s: &str) -> (i32, Vec<i32>) {
+    let mut lines = s.lines();
+    let t = lines.next().unwrap().parse().unwrap();
+    let b = lines.next().unwrap()
+        .split(',')
+        .filter_map(|id| id.parse().ok())
+        .collect();
+    (t, b)
+}
+
+fn part1(input: &str) -> i32 {
+    let (t, b) = parse_1(input);
+    let (id, time) = b.iter()
+        // find next departure time after start
+        .map(|&id| (id, t - (t % id) + id))
+        // find bus with closest departure time
+        .min_by_key(|(_, departure)| *departure)
+        .unwrap();
+
+    let wait = time - t;
+
+    id * wait
+}
+
+/// The buses as congruences `t ≡ -offset (mod id)` for the time `t`.
+fn parse_2(s: &str) -> Vec<(i64, i64)> {
+    s.lines().nth(1).unwrap()
+        .split(',')
+        .enumerate()
+        .filter(|(_, id)| *id != "x")
+        .map(|(offset, id)| (-(offset as i64), id.parse().unwrap()))
+        .collect()
+}
+
+fn part2(input: &str) -> i64 {
+    let b = parse_2(input);
+
+    math::crt(&b).unwrap().0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::examples! {
+        Solver;
+        notes: "939
+7,13,x,x,59,x,31,19" => part1 = 295, part2 = 1068781;
+        schedule_1: "0
+17,x,13,19" => part2 = 3417;
+        schedule_2: "0
+67,7,59,61" => part2 = 754018;
+        schedule_3: "0
+67,x,7,59,61" => part2 = 779210;
+        schedule_4: "0
+67,7,x,59,61" => part2 = 1261476;
+        schedule_5: "0
+1789,37,47,1889" => part2 = 1202161486;
+    }
+}
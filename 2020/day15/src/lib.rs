@@ -0,0 +1,69 @@
+use std::collections::HashMap;
+
+use aoc::{Answer, Solution};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2020;
+    const DAY: u32 = 15;
+    const INPUT: Option<&'static str> = Some("7,14,0,17,11,1,2");
+
+    type Input<'a> = Vec<i32>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.trim_end().split(',').map(|n| n.parse().unwrap()).collect()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+fn play(input: &[i32], final_turn: usize) -> i32 {
+    let mut seen: HashMap<i32, usize> = input[..input.len()-1].iter().enumerate()
+        .map(|(t, &n)| (n, t))
+        .collect();
+
+    let mut last_spoken = *input.last().unwrap();
+    for turn in input.len()..final_turn {
+        if let Some(&t) = seen.get(&last_spoken) {
+            let diff = turn - 1 - t;
+            seen.insert(last_spoken, turn - 1);
+            last_spoken = diff as i32;
+        } else {
+            seen.insert(last_spoken, turn - 1);
+            last_spoken = 0;
+        }
+    }
+
+    last_spoken
+}
+
+fn part1(input: &[i32]) -> i32 {
+    play(input, 2020)
+}
+
+fn part2(input: &[i32]) -> i32 {
+    play(input, 30_000_000)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::examples! {
+        Solver;
+        example_1: "0,3,6" => part1 = 436, part2 = 175594;
+        example_2: "1,3,2" => part1 = 1;
+        example_3: "2,1,3" => part1 = 10;
+        example_4: "1,2,3" => part1 = 27;
+        example_5: "2,3,1" => part1 = 78;
+        example_6: "3,2,1" => part1 = 438;
+        example_7: "3,1,2" => part1 = 1836;
+    }
+}
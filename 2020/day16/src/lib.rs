@@ -0,0 +1,164 @@
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+
+use aoc::{Answer, Solution};
+
+type TicketRules = HashMap<String, Vec<RangeInclusive<i64>>>;
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2020;
+    const DAY: u32 = 16;
+
+    type Input<'a> = Notes;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[derive(Debug)]
+pub struct Notes {
+    rules: TicketRules,
+    ticket: Vec<i64>,
+    other_tickets: Vec<Vec<i64>>,
+}
+
+fn parse(s: &str) -> Notes {
+    let mut sections = s.split("\n\n");
+
+    let rules = sections.next().unwrap().lines().map(|l| {
+        let mut parts = l.split(": ");
+        let field = parts.next().unwrap().to_string();
+        let ranges = parts.next().unwrap().split(" or ")
+            .map(|range| {
+                let sep = range.find('-').unwrap();
+                let start = range[..sep].parse().unwrap();
+                let end = range[sep+1..].parse().unwrap();
+                 start ..= end
+            })
+            .collect();
+        (field, ranges)
+    })
+    .collect();
+
+    let ticket = sections.next().unwrap()
+        .lines().nth(1).unwrap()
+        .split(',').map(|n| n.parse().unwrap())
+        .collect();
+
+    let other_tickets = sections.next().unwrap()
+        .lines().skip(1)
+        .map(|l| l.split(',').map(|n| n.parse().unwrap()).collect())
+        .collect();
+
+    Notes {
+        rules,
+        ticket,
+        other_tickets,
+    }
+}
+
+fn is_field_possible(ranges: &[RangeInclusive<i64>], n: i64) -> bool {
+    ranges.iter().any(|r| r.contains(&n))
+}
+
+fn is_value_valid(rules: &TicketRules, n: i64) -> bool {
+    // is any rule valid?
+    rules.iter().any(|(_, ranges)| {
+        // is any range of this field valid?
+        is_field_possible(ranges, n)
+    })
+}
+
+fn part1(input: &Notes) -> i64 {
+    input.other_tickets.iter().flat_map(|ticket| {
+        // find invalid values
+        ticket.iter().filter(|&&n| !is_value_valid(&input.rules, n))
+    }).sum()
+}
+
+fn is_ticket_valid(rules: &TicketRules, ticket: &[i64]) -> bool {
+    ticket.iter().all(|&n| is_value_valid(rules, n))
+}
+
+fn part2(input: &Notes) -> i64 {
+    let valid_tickets: Vec<_> = input.other_tickets.iter()
+        .filter(|&ticket| is_ticket_valid(&input.rules, ticket)).collect();
+
+    let num_fields = input.rules.keys().count();
+
+    // maps fields to histograms of
+    // `ticket value index` <=> `# of tickets where this field at this index is valid`
+    let mut histograms: HashMap<&String, Vec<usize>> = HashMap::new();
+
+    for (field, ranges) in input.rules.iter() {
+        let histogram = histograms.entry(field)
+            .or_insert_with(|| vec![0; num_fields]);
+
+        for (index, count) in histogram.iter_mut().enumerate() {
+            // Count tickets where the value is valid for this field in the current position
+            *count += valid_tickets.iter()
+                .filter(|&&ticket| is_field_possible(ranges, ticket[index]))
+                .count();
+        }
+    }
+
+    let max_count = valid_tickets.len();
+    let mut index_map = HashMap::new();
+
+    while index_map.len() != num_fields {
+        for (field, hist) in histograms.iter() {
+            let mut best_index = usize::MAX;
+            let possible_indices = hist.iter().enumerate().filter(|(index, &count)| {
+                if count == max_count && !index_map.contains_key(index) {
+                    best_index = *index;
+                    return true
+                }
+                false
+            }).count();
+
+            if possible_indices == 1 {
+                index_map.insert(best_index, field);
+            }
+        }
+    }
+
+    index_map.iter().filter_map(|(index, field)| {
+        if field.starts_with("departure") {
+            Some(input.ticket[*index])
+        } else {
+            None
+        }
+    }).product()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::examples! {
+        Solver;
+        error_rate: "class: 1-3 or 5-7
+row: 6-11 or 33-44
+seat: 13-40 or 45-50
+
+your ticket:
+7,1,14
+
+nearby tickets:
+7,3,47
+40,4,50
+55,2,20
+38,6,12" => part1 = 71;
+    }
+}
@@ -0,0 +1,66 @@
+use aoc::{Answer, Solution};
+use automaton::Sparse;
+use geometry::{Point2, Point3, Point4};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2020;
+    const DAY: u32 = 17;
+
+    type Input<'a> = Vec<Point2>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+/// The active cubes in the starting slice.
+fn parse(s: &str) -> Vec<Point2> {
+    s.lines().enumerate().flat_map(|(y, l)| {
+        l.char_indices().filter_map(move |(x, c)| match c {
+            '#' => Some(Point2::new(x as i64, y as i64)),
+            _ => None,
+        })
+    }).collect()
+}
+
+const CYCLES: usize = 6;
+
+fn rule(active: bool, neighbors: usize) -> bool {
+    neighbors == 3 || (active && neighbors == 2)
+}
+
+fn part1(slice: &[Point2]) -> usize {
+    let cubes = slice.iter().map(|p| Point3::new(p.x, p.y, 0));
+    let mut space = Sparse::new(cubes, Point3::neighbors);
+    space.run(CYCLES, rule);
+    space.live().len()
+}
+
+fn part2(slice: &[Point2]) -> usize {
+    let cubes = slice.iter().map(|p| Point4::new(p.x, p.y, 0, 0));
+    let mut space = Sparse::new(cubes, Point4::neighbors);
+    space.run(CYCLES, rule);
+    space.live().len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::examples! {
+        Solver;
+        glider: ".#.
+..#
+###" => part1 = 112, part2 = 848;
+    }
+}
@@ -0,0 +1,66 @@
+use aoc::{Answer, Solution};
+use expr::{Assoc, Table};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2020;
+    const DAY: u32 = 18;
+
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.lines().collect()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+/// Addition and multiplication are evaluated left to right.
+fn ruleset_1() -> Table {
+    Table::new()
+        .op('+', 1, Assoc::Left)
+        .op('*', 1, Assoc::Left)
+}
+
+/// Addition is evaluated before multiplication.
+fn ruleset_2() -> Table {
+    Table::new()
+        .op('+', 2, Assoc::Left)
+        .op('*', 1, Assoc::Left)
+}
+
+fn sum(exprs: &[&str], table: &Table) -> u64 {
+    exprs.iter()
+        .map(|expr| table.parse(expr).unwrap().eval::<u64>(|_| None).unwrap())
+        .sum()
+}
+
+fn part1(exprs: &[&str]) -> u64 {
+    sum(exprs, &ruleset_1())
+}
+
+fn part2(exprs: &[&str]) -> u64 {
+    sum(exprs, &ruleset_2())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::examples! {
+        Solver;
+        example_1: "1 + 2 * 3 + 4 * 5 + 6" => part1 = 71, part2 = 231;
+        example_2: "1 + (2 * 3) + (4 * (5 + 6))" => part1 = 51, part2 = 51;
+        example_3: "2 * 3 + (4 * 5)" => part1 = 26, part2 = 46;
+        example_4: "5 + (8 * 3 + 9 + 3 * 4 * 3)" => part1 = 437, part2 = 1445;
+        example_5: "5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))" => part1 = 12240, part2 = 669060;
+        example_6: "((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2" => part1 = 13632, part2 = 23340;
+    }
+}
@@ -0,0 +1,132 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use aoc::{Answer, Solution};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2020;
+    const DAY: u32 = 21;
+
+    type Input<'a> = Vec<Food>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        scan::or_panic(scan::lines(input, str::parse))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Food {
+    ingredients: Vec<String>,
+    allergens: Vec<String>,
+}
+
+impl std::str::FromStr for Food {
+    type Err = scan::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (ingredients, allergens) = scan::scan!(s, "{} (contains {})" => &str, &str)?;
+        Ok(Self {
+            ingredients: ingredients.split(' ').map(ToOwned::to_owned).collect(),
+            allergens: allergens.split(", ").map(ToOwned::to_owned).collect(),
+        })
+    }
+}
+
+fn analyze_foods(foods: &[Food]) -> (BTreeMap<&String, HashMap<&String, u32>>, HashSet<&String>) {
+    let mut allergen_counts: BTreeMap<&String, HashMap<&String, u32>> = BTreeMap::new();
+    let mut anti_candidates = HashSet::new();
+    for Food { ingredients, allergens } in foods.iter() {
+        for allergen in allergens.iter() {
+            let counts= allergen_counts.entry(allergen).or_default();
+            for ingredient in ingredients.iter() {
+                *counts.entry(ingredient).or_default() += 1;
+            }
+        }
+        anti_candidates.extend(ingredients);
+    }
+
+    for (_, counts) in allergen_counts.iter_mut() {
+        let (_, max) = counts.iter().max_by_key(|(_, count)| **count).unwrap();
+        let mut remove = Vec::new();
+        for (ingredient, count) in counts.iter() {
+            if count == max {
+                anti_candidates.remove(ingredient);
+            } else {
+                remove.push(*ingredient);
+            }
+        }
+        for ingredient in remove.iter() {
+            counts.remove(ingredient);
+        }
+    }
+
+    (allergen_counts, anti_candidates)
+}
+
+fn part1(foods: &[Food]) -> usize {
+    let (_, anti_candidates) = analyze_foods(foods);
+
+    let mut total = 0;
+    for Food { ingredients, .. } in foods.iter() {
+        for ingredient in ingredients.iter() {
+            if anti_candidates.contains(ingredient) {
+                total += 1;
+            }
+        }
+    }
+
+    total
+}
+
+fn part2(foods: &[Food]) -> String {
+    let (mut allergen_counts, anti_candidates) = analyze_foods(foods);
+
+    let mut all_ingredients: HashSet<&String> = HashSet::new();
+
+    for (_, counts) in allergen_counts.iter_mut() {
+        for ac in anti_candidates.iter() {
+            counts.remove(ac);
+        }
+
+        all_ingredients.extend(counts.keys());
+    }
+
+    let mut ingredients: BTreeMap<&String, &String> = BTreeMap::new();
+
+    while ingredients.len() != all_ingredients.len() {
+        for (allergen, counts) in allergen_counts.iter() {
+            if counts.len() == 1 && !ingredients.contains_key(allergen) {
+                ingredients.insert(allergen, counts.keys().next().unwrap());
+            }
+        }
+        for (_, counts) in allergen_counts.iter_mut() {
+            for ingredient in ingredients.values() {
+                counts.remove(ingredient);
+            }
+        }
+    }
+
+    ingredients.into_values().cloned().collect::<Vec<_>>().join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::examples! {
+        Solver;
+        foods: "mxmxvkd kfcds sqjhc nhms (contains dairy, fish)
+trh fvjkl sbzzf mxmxvkd (contains dairy)
+sqjhc fvjkl (contains soy)
+sqjhc mxmxvkd sbzzf (contains fish)" => part1 = 5, part2 = "mxmxvkd,sqjhc,fvjkl";
+    }
+}
@@ -0,0 +1,53 @@
+use aoc::{Answer, Solution};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 1;
+
+    type Input<'a> = Vec<u32>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.lines().map(|l| l.parse().unwrap()).collect()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+fn part1(input: &[u32]) -> usize {
+    input.windows(2).filter(|w| w[1] > w[0]).count()
+}
+
+fn part2(input: &[u32]) -> usize {
+    let windows: Vec<&[u32]> = input.windows(3).collect();
+    windows
+        .windows(2)
+        .filter(|ws| ws[1].iter().sum::<u32>() > ws[0].iter().sum())
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::examples! {
+        Solver;
+        sonar_sweep: "199
+200
+208
+210
+200
+207
+240
+269
+260
+263" => part1 = 7, part2 = 5;
+    }
+}
@@ -0,0 +1,73 @@
+use aoc::{Answer, Solution};
+use geometry::{Dir4, Point2};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 2;
+
+    type Input<'a> = Vec<Command>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.lines().map(parse_command).collect()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+/// A move of the submarine, with depth growing southwards: forward is east,
+/// up is north and down is south.
+pub struct Command {
+    dir: Dir4,
+    n: i64,
+}
+
+fn parse_command(c: &str) -> Command {
+    let (cmd, n) = c.split_once(' ').unwrap();
+    let dir = match cmd {
+        "forward" => Dir4::E,
+        "up" => Dir4::N,
+        "down" => Dir4::S,
+        _ => panic!("Unknown command: {}", cmd),
+    };
+    Command { dir, n: n.parse().unwrap() }
+}
+
+fn part1(input: &[Command]) -> i64 {
+    let pos = input
+        .iter()
+        .fold(Point2::ORIGIN, |pos, cmd| pos + cmd.dir.step() * cmd.n);
+    pos.x * pos.y
+}
+
+fn part2(input: &[Command]) -> i64 {
+    let (pos, _) = input
+        .iter()
+        .fold((Point2::ORIGIN, 0), |(pos, aim), cmd| match cmd.dir {
+            Dir4::E => (pos + Point2::new(cmd.n, aim * cmd.n), aim),
+            dir => (pos, aim + dir.step().y * cmd.n),
+        });
+    pos.x * pos.y
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::examples! {
+        Solver;
+        course: "forward 5
+down 5
+forward 8
+up 3
+down 8
+forward 2" => part1 = 150, part2 = 900;
+    }
+}
@@ -0,0 +1,90 @@
+use aoc::{Answer, Solution};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 3;
+
+    /// The numbers and the number of bits in each of them.
+    type Input<'a> = (Vec<u32>, usize);
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        let bits = input.lines().next().unwrap().len();
+        let nums = input.lines().map(|l| u32::from_str_radix(l, 2).unwrap()).collect();
+        (nums, bits)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(&input.0, input.1).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(&input.0, input.1).into()
+    }
+}
+
+fn part1(input: &[u32], bits: usize) -> u32 {
+    let counts = input.iter().fold(vec![0; bits], |mut counts, n| {
+        for (i, count) in counts.iter_mut().enumerate() {
+            *count += (n >> i) & 1;
+        }
+        counts
+    });
+    let min = input.len() as u32 / 2 + 1;
+    let mut gamma = 0;
+    for (i, &count) in counts.iter().enumerate() {
+        let bit = u32::from(count >= min);
+        gamma |= bit << i;
+    }
+
+    // invert and keep the lower `bits` bits
+    let epsilon = (!gamma) & (2u32.pow(bits as u32) - 1);
+
+    gamma * epsilon
+}
+
+fn part2(input: &[u32], bits: usize) -> u32 {
+    let mut oxg_nums = input.to_vec();
+    let mut oxg_idx = bits - 1;
+    while oxg_nums.len() > 1 {
+        let count1 = oxg_nums.iter().fold(0, |count, n| count + ((n >> oxg_idx) & 1));
+        let count0 = oxg_nums.len() as u32 - count1;
+        let bit = u32::from(count1 >= count0);
+        oxg_nums.retain(|n| ((n >> oxg_idx) & 1) == bit);
+        oxg_idx = oxg_idx.saturating_sub(1);
+    }
+
+    let mut co2_nums = input.to_vec();
+    let mut co2_idx = bits - 1;
+    while co2_nums.len() > 1 {
+        let count1 = co2_nums.iter().fold(0, |count, n| count + ((n >> co2_idx) & 1));
+        let count0 = co2_nums.len() as u32 - count1;
+        let bit = u32::from(count0 > count1);
+        co2_nums.retain(|n| ((n >> co2_idx) & 1) == bit);
+        co2_idx = co2_idx.saturating_sub(1);
+    }
+
+    oxg_nums.into_iter().zip(co2_nums).map(|(oxg, co2)| oxg * co2).next().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::examples! {
+        Solver;
+        diagnostic: "00100
+11110
+10110
+10111
+10101
+01111
+00111
+11100
+10000
+11001
+00010
+01010" => part1 = 198, part2 = 230;
+    }
+}
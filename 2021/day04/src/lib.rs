@@ -0,0 +1,140 @@
+use aoc::{Answer, Solution};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 4;
+
+    type Input<'a> = (Vec<u32>, Vec<Board>);
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        let mut blocks = scan::blocks(input);
+
+        let numbers = scan::or_panic(blocks.next().unwrap().parse(scan::list));
+
+        let boards = blocks.map(|b| scan::or_panic(b.parse(str::parse))).collect();
+
+        (numbers, boards)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(&input.0, input.1.clone()).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(&input.0, input.1.clone()).into()
+    }
+}
+
+#[derive(Clone)]
+pub struct Board {
+    numbers: Vec<Vec<(u32, bool)>>,
+}
+
+impl std::str::FromStr for Board {
+    type Err = scan::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let numbers = scan::lines(s, scan::list::<u32>)?
+            .into_iter()
+            .map(|row| row.into_iter().map(|n| (n, false)).collect())
+            .collect();
+        Ok(Self { numbers })
+    }
+}
+
+impl Board {
+    fn mark(&mut self, number: u32) {
+        for row in self.numbers.iter_mut() {
+            for (n, m) in row.iter_mut() {
+                if *n == number {
+                    *m = true;
+                }
+            }
+        }
+    }
+
+    fn score(&self) -> u32 {
+        self.numbers
+            .iter()
+            .flat_map(|row| row.iter())
+            .filter(|(_, m)| !m)
+            .map(|(n, _)| *n)
+            .sum()
+    }
+
+    fn wins(&self) -> Option<u32> {
+        let size = self.numbers.len();
+        for i in 0..size {
+            let mut row_marked = true;
+            let mut col_marked = true;
+            for j in 0..size {
+                row_marked = row_marked && self.numbers[i][j].1;
+                col_marked = col_marked && self.numbers[j][i].1;
+            }
+            if row_marked || col_marked {
+                return Some(self.score());
+            }
+        }
+        None
+    }
+}
+
+fn part1(numbers: &[u32], mut boards: Vec<Board>) -> u32 {
+    for &n in numbers {
+        for board in boards.iter_mut() {
+            board.mark(n);
+            if let Some(score) = board.wins() {
+                return n * score;
+            }
+        }
+    }
+    unreachable!()
+}
+
+fn part2(numbers: &[u32], mut boards: Vec<Board>) -> u32 {
+    let mut winners = Vec::new();
+    for &n in numbers {
+        for i in 0..boards.len() {
+            if winners.contains(&i) { continue; }
+            boards[i].mark(n);
+            if let Some(score) = boards[i].wins() {
+                if boards.len() - winners.len() == 1 {
+                    return n * score;
+                } else {
+                    winners.push(i);
+                }
+            }
+        }
+    }
+    unreachable!()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::examples! {
+        Solver;
+        bingo: "7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19
+
+ 3 15  0  2 22
+ 9 18 13 17  5
+19  8  7 25 23
+20 11 10 24  4
+14 21 16 12  6
+
+14 21 17 24  4
+10 16 15  9 19
+18  8 23 26 20
+22 11 13  6  5
+ 2  0 12  3  7" => part1 = 4512, part2 = 1924;
+    }
+}
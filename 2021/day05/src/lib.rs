@@ -0,0 +1,92 @@
+use std::collections::HashMap;
+
+use aoc::{Answer, Solution};
+
+type Line = (i32, i32, i32, i32);
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 5;
+
+    type Input<'a> = Vec<Line>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+            .lines()
+            .map(|l| {
+                let mut coords = l
+                    .split(" -> ")
+                    .flat_map(|c| c.split(',').map(|n| n.parse().unwrap()));
+                (
+                    coords.next().unwrap(),
+                    coords.next().unwrap(),
+                    coords.next().unwrap(),
+                    coords.next().unwrap(),
+                )
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+fn count_overlaps(lines: &[Line], diagonals: bool) -> usize {
+    let mut counts = HashMap::new();
+    for &(x0, y0, x1, y1) in lines {
+        if x0 == x1 {
+            for y in i32::min(y0, y1)..=i32::max(y0, y1) {
+                *counts.entry((x0, y)).or_insert(0) += 1;
+            }
+        } else if y0 == y1 {
+            for x in i32::min(x0, x1)..=i32::max(x0, x1) {
+                *counts.entry((x, y0)).or_insert(0) += 1;
+            }
+        } else if diagonals {
+            let dx = if x0 > x1 { -1 } else { 1 };
+            let dy = if y0 > y1 { -1 } else { 1 };
+            let mut x = x0;
+            let mut y = y0;
+            while x != x1 + dx && y != y1 + dy {
+                *counts.entry((x, y)).or_insert(0) += 1;
+                x += dx;
+                y += dy;
+            }
+        }
+    }
+    counts.iter().filter(|(_, c)| **c >= 2).count()
+}
+
+fn part1(lines: &[Line]) -> usize {
+    count_overlaps(lines, false)
+}
+
+fn part2(lines: &[Line]) -> usize {
+    count_overlaps(lines, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::examples! {
+        Solver;
+        vents: "0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2" => part1 = 5, part2 = 12;
+    }
+}
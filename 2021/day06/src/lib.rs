@@ -0,0 +1,63 @@
+use aoc::{Answer, Solution};
+use automaton::Population;
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 6;
+
+    type Input<'a> = Vec<u8>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+            .trim()
+            .split(',')
+            .map(|n| n.parse().unwrap())
+            .collect()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+fn simulate(fish: &[u8], days: usize) -> u64 {
+    // A fish with a timer of 0 resets to 6 and makes a new one at 8
+    let mut transitions: Vec<Vec<usize>> = (0..9).map(|timer| vec![timer.max(1) - 1]).collect();
+    transitions[0] = vec![6, 8];
+
+    let mut school = Population::new(transitions);
+    for &timer in fish {
+        school.add(timer as usize, 1);
+    }
+    school.run(days);
+    school.total()
+}
+
+fn part1(fish: &[u8]) -> u64 {
+    simulate(fish, 80)
+}
+
+fn part2(fish: &[u8]) -> u64 {
+    simulate(fish, 256)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::examples! {
+        Solver;
+        lanternfish: "3,4,3,1,2" => part1 = 5934, part2 = 26984457539u64;
+    }
+
+    #[test]
+    fn test_simulate() {
+        assert_eq!(simulate(&[3, 4, 3, 1, 2], 18), 26);
+    }
+}
@@ -0,0 +1,117 @@
+use std::fmt;
+
+/// The answer to one part of a puzzle.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Answer {
+    Int(i128),
+    Str(String),
+    /// Letter art, as rows separated by newlines.
+    Grid(String),
+    /// A part that has not been solved yet.
+    Unsolved,
+}
+
+impl Answer {
+    /// Build a grid answer from rows of pixels, drawing lit pixels as `#`.
+    pub fn grid<R, I>(rows: R) -> Self
+    where
+        R: IntoIterator<Item = I>,
+        I: IntoIterator<Item = bool>,
+    {
+        let rows: Vec<String> = rows
+            .into_iter()
+            .map(|row| row.into_iter().map(|lit| if lit { '#' } else { '.' }).collect())
+            .collect();
+        Answer::Grid(rows.join("\n"))
+    }
+
+    pub fn as_int(&self) -> Option<i128> {
+        match *self {
+            Answer::Int(n) => Some(n),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::Str(s) | Answer::Grid(s) => f.write_str(s),
+            Answer::Unsolved => f.write_str("unsolved"),
+        }
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Int(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Str(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Str(s.to_string())
+    }
+}
+
+impl PartialEq<i128> for Answer {
+    fn eq(&self, other: &i128) -> bool {
+        self.as_int() == Some(*other)
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        match self {
+            Answer::Str(s) | Answer::Grid(s) => s == other,
+            Answer::Int(_) | Answer::Unsolved => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from() {
+        assert_eq!(Answer::from(42usize), Answer::Int(42));
+        assert_eq!(Answer::from(-7i64), Answer::Int(-7));
+        assert_eq!(Answer::from("abc"), Answer::Str("abc".to_string()));
+        assert_eq!(Answer::from(u64::MAX), Answer::Int(u64::MAX as i128));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::Int(-12).to_string(), "-12");
+        assert_eq!(Answer::Str("hxbxxyzz".to_string()).to_string(), "hxbxxyzz");
+
+        let grid = Answer::grid(vec![vec![true, false], vec![false, true]]);
+        assert_eq!(grid, Answer::Grid("#.\n.#".to_string()));
+        assert_eq!(grid.to_string(), "#.\n.#");
+        assert_eq!(Answer::Unsolved.to_string(), "unsolved");
+    }
+
+    #[test]
+    fn test_compare() {
+        assert_eq!(Answer::Int(5), 5);
+        assert_eq!(Answer::from("abc"), "abc");
+        assert_ne!(Answer::Int(5), "5");
+        assert_eq!(Answer::Str("5".to_string()).as_int(), None);
+    }
+}
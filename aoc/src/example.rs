@@ -0,0 +1,55 @@
+use crate::{Answer, Solution};
+
+/// Declare tests that run a solution on the worked examples from the puzzle
+/// text and compare its answers with the expected ones.
+///
+/// Each example becomes a `#[test]` with the given name and checks only the
+/// parts that are listed, since puzzles often use a different example for
+/// each part. Short examples are written inline; longer ones, or ones where
+/// whitespace matters, go in a file next to the day's `input` and are
+/// included with `include_str!`. Attributes like `#[ignore]` go before the
+/// name of an example.
+///
+/// ```ignore
+/// #[cfg(test)]
+/// mod tests {
+///     use super::*;
+///
+///     aoc::examples! {
+///         Solver;
+///         simple: "(())" => part1 = 0;
+///         basement: "()())" => part2 = 5;
+///         orbits: include_str!("../example") => part1 = 54, part2 = 4;
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! examples {
+    ($solver:ty; $($(#[$attr:meta])* $name:ident: $input:expr => $($part:ident = $expected:expr),+;)+) => {
+        $(
+            #[test]
+            $(#[$attr])*
+            fn $name() {
+                let input: &str = $input;
+                $(
+                    $crate::example::check::<$solver>(
+                        input,
+                        stringify!($part),
+                        $crate::Answer::from($expected),
+                    );
+                )+
+            }
+        )+
+    };
+}
+
+/// Check one part of an example, for use by [`examples!`].
+#[doc(hidden)]
+pub fn check<S: Solution>(input: &str, part: &str, expected: Answer) {
+    let n = match part {
+        "part1" => 1,
+        "part2" => 2,
+        part => panic!("Unknown part: {}", part),
+    };
+    assert_eq!(crate::solve::<S>(input, n), expected, "{} of the example", part);
+}
@@ -0,0 +1,188 @@
+//! Finding and reading puzzle inputs.
+//!
+//! Unless a source is given explicitly, the input of a day is looked for in
+//! order in:
+//!
+//! 1. the input store, as `<year>/day<DD>.txt`. The store is the directory
+//!    named by the `AOC_INPUT_DIR` environment variable, or `inputs` at the
+//!    root of the repository;
+//! 2. the `input` file in the day's directory;
+//! 3. the input embedded in the solution.
+
+use std::env;
+use std::error;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// The environment variable naming the input store.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// The directory of the input store.
+pub fn store_dir() -> PathBuf {
+    match env::var_os(INPUT_DIR_VAR) {
+        Some(dir) => PathBuf::from(dir),
+        None => Path::new(env!("CARGO_MANIFEST_DIR")).join("../inputs"),
+    }
+}
+
+/// The path of a day's input in the store.
+pub fn store_path(year: u32, day: u32) -> PathBuf {
+    store_dir().join(year.to_string()).join(format!("day{:02}.txt", day))
+}
+
+/// The 64-bit FNV-1a hash of an input in hex, which identifies it without
+/// publishing it.
+pub fn hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf2_9ce4_8422_2325u64, |hash, b| {
+        (hash ^ b as u64).wrapping_mul(0x0000_0100_0000_01b3)
+    });
+    format!("{:016x}", hash)
+}
+
+/// A place to read an input from.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Source {
+    Path(PathBuf),
+    Stdin,
+    Inline(String),
+}
+
+impl Source {
+    /// Interpret a command line argument: `-` for stdin, or a path.
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => Source::Stdin,
+            path => Source::Path(PathBuf::from(path)),
+        }
+    }
+
+    fn read(&self) -> io::Result<String> {
+        match self {
+            Source::Path(path) => fs::read_to_string(path),
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+            Source::Inline(input) => Ok(input.clone()),
+        }
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Source::Path(path) => write!(f, "{}", path.display()),
+            Source::Stdin => f.write_str("stdin"),
+            Source::Inline(_) => f.write_str("embedded input"),
+        }
+    }
+}
+
+/// No input could be read for a day.
+#[derive(Debug)]
+pub struct Error {
+    pub year: u32,
+    pub day: u32,
+    /// Every source that was tried, with the reason it could not be read.
+    pub tried: Vec<(Source, io::Error)>,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "No input for {} day {}", self.year, self.day)?;
+        if self.tried.is_empty() {
+            return f.write_str(": nowhere to look for it");
+        }
+        f.write_str(", looked in:")?;
+        for (source, e) in &self.tried {
+            write!(f, "\n    {}: {}", source, e)?;
+        }
+        Ok(())
+    }
+}
+
+impl error::Error for Error {}
+
+/// The sources to try for the input of a day, in order.
+#[derive(Clone, Debug)]
+pub struct Lookup {
+    year: u32,
+    day: u32,
+    sources: Vec<Source>,
+}
+
+impl Lookup {
+    /// Read only from the given source.
+    pub fn explicit(year: u32, day: u32, source: Source) -> Self {
+        Self { year, day, sources: vec![source] }
+    }
+
+    /// Look in the default places, given the day's directory and its
+    /// embedded input.
+    pub fn new(year: u32, day: u32, dir: &Path, inline: Option<&str>) -> Self {
+        let mut sources = vec![Source::Path(store_path(year, day)), Source::Path(dir.join("input"))];
+        if let Some(input) = inline {
+            sources.push(Source::Inline(input.to_string()));
+        }
+        Self { year, day, sources }
+    }
+
+    pub fn sources(&self) -> &[Source] {
+        &self.sources
+    }
+
+    /// Read the input from the first source that can be read.
+    pub fn read(&self) -> Result<String, Error> {
+        self.read_with_source().map(|(_, input)| input)
+    }
+
+    /// Read the input from the first source that can be read, and tell
+    /// which one that was.
+    pub fn read_with_source(&self) -> Result<(&Source, String), Error> {
+        let mut tried = Vec::new();
+        for source in &self.sources {
+            match source.read() {
+                Ok(input) => return Ok((source, input)),
+                Err(e) => tried.push((source.clone(), e)),
+            }
+        }
+        Err(Error { year: self.year, day: self.day, tried })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(Source::from_arg("-"), Source::Stdin);
+        assert_eq!(Source::from_arg("input"), Source::Path(PathBuf::from("input")));
+    }
+
+    #[test]
+    fn test_hash() {
+        assert_eq!(hash(""), "cbf29ce484222325");
+        assert_eq!(hash("a"), "af63dc4c8601ec8c");
+        assert_eq!(hash("foobar"), "85944171f73967e8");
+    }
+
+    #[test]
+    fn test_fallback() {
+        let dir = Path::new("/nonexistent/2015/day04");
+        let lookup = Lookup::new(2015, 4, dir, Some("ckczppom"));
+        let (source, input) = lookup.read_with_source().unwrap();
+        assert_eq!(*source, Source::Inline("ckczppom".to_string()));
+        assert_eq!(input, "ckczppom");
+
+        let lookup = Lookup::explicit(2015, 4, Source::Path(dir.join("input")));
+        let e = lookup.read().unwrap_err();
+        assert_eq!(e.tried.len(), 1);
+        assert!(e.to_string().starts_with(
+            "No input for 2015 day 4, looked in:\n    /nonexistent/2015/day04/input: "
+        ));
+    }
+}
@@ -0,0 +1,84 @@
+//! Common interface for the Advent of Code solutions in this repository.
+//!
+//! Every day implements [`Solution`], so its answers can be computed by the
+//! day's own binary as well as by the `aoc` runner.
+
+use std::env;
+use std::path::Path;
+use std::process;
+
+mod answer;
+#[doc(hidden)]
+pub mod example;
+pub mod input;
+
+pub use answer::Answer;
+use input::{Lookup, Source};
+
+/// A solution to a single day's puzzle.
+pub trait Solution {
+    const YEAR: u32;
+    const DAY: u32;
+
+    /// The number of parts that have been solved.
+    const PARTS: u32 = 2;
+
+    /// The puzzle input, for days where it is part of the source instead
+    /// of an `input` file.
+    const INPUT: Option<&'static str> = None;
+
+    /// The parsed puzzle input shared by both parts.
+    type Input<'a>;
+
+    fn parse(input: &str) -> Self::Input<'_>;
+
+    fn part1(input: &Self::Input<'_>) -> Answer;
+
+    /// The second part, which is [`Answer::Unsolved`] until `PARTS` is 2.
+    fn part2(input: &Self::Input<'_>) -> Answer;
+}
+
+/// Solve the given part of a puzzle. Parts after `S::PARTS` are
+/// [`Answer::Unsolved`] without running them.
+pub fn solve<S: Solution>(input: &str, part: u32) -> Answer {
+    if part == 2 && S::PARTS < 2 {
+        return Answer::Unsolved;
+    }
+    let input = S::parse(input);
+    match part {
+        1 => S::part1(&input),
+        2 => S::part2(&input),
+        n => panic!("Unknown part: {}", n),
+    }
+}
+
+/// Read the puzzle input of a day, exiting with the reason if there is
+/// none.
+///
+/// The input is read from the path given as `arg`, or from stdin if it is
+/// `-`. Otherwise it is looked for as described in [`input`], with the
+/// current directory as the day's directory.
+pub fn read_input<S: Solution>(arg: Option<&str>) -> String {
+    let lookup = match arg {
+        Some(arg) => Lookup::explicit(S::YEAR, S::DAY, Source::from_arg(arg)),
+        None => Lookup::new(S::YEAR, S::DAY, Path::new("."), S::INPUT),
+    };
+    lookup.read().unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1);
+    })
+}
+
+/// Read the puzzle input and print the answers.
+///
+/// The input is read as by [`read_input`], with the first argument as the
+/// path.
+pub fn main<S: Solution>() {
+    let input = read_input::<S>(env::args().nth(1).as_deref());
+    let input = S::parse(&input);
+
+    println!("{}", S::part1(&input));
+    if S::PARTS > 1 {
+        println!("{}", S::part2(&input));
+    }
+}
@@ -0,0 +1,49 @@
+//! The answers recorded in `answers.json` at the root of the repository.
+//!
+//! The file maps years to days to a list with an answer per part. Numbers
+//! and strings are compared with the displayed answer; letter art is stored
+//! as a list of rows. An empty list marks a day whose answers are not known
+//! yet, as created by `aoc new`.
+
+use std::fs;
+
+use serde_json::Value;
+
+use crate::days;
+
+pub struct Answers {
+    json: Value,
+}
+
+/// The recorded answer as it would be displayed.
+fn expected(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Array(rows) => rows
+            .iter()
+            .map(|row| row.as_str().unwrap_or_default())
+            .collect::<Vec<_>>()
+            .join("\n"),
+        value => value.to_string(),
+    }
+}
+
+impl Answers {
+    pub fn load() -> Result<Self, String> {
+        let path = days::root().join("answers.json");
+        let json = fs::read_to_string(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        let json = serde_json::from_str(&json).map_err(|e| format!("{}: {}", path.display(), e))?;
+        Ok(Self { json })
+    }
+
+    /// The recorded answers of a day, as they would be displayed, or `None`
+    /// if the day has no entry.
+    pub fn get(&self, year: u32, day: u32) -> Option<Vec<String>> {
+        let recorded = self
+            .json
+            .get(year.to_string())?
+            .get(day.to_string())?
+            .as_array()?;
+        Some(recorded.iter().map(expected).collect())
+    }
+}
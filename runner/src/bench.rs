@@ -0,0 +1,281 @@
+//! Timing of the parse step and each part of a solution, and baseline files
+//! to compare the timings against later.
+//!
+//! A baseline file has a line per day with the year, the day and the times
+//! of parsing and of each part in nanoseconds:
+//!
+//! ```text
+//! 2015 9 48200 1503000 1498000
+//! ```
+
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use aoc::Solution;
+
+/// How often each step is run before and while it is timed.
+#[derive(Clone, Copy, Debug)]
+pub struct Settings {
+    pub warmup: u32,
+    pub runs: u32,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self { warmup: 1, runs: 5 }
+    }
+}
+
+/// The median time of parsing the input and of each part.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Timings {
+    pub parse: Duration,
+    pub parts: Vec<Duration>,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.parts.iter().sum::<Duration>()
+    }
+}
+
+/// A timed day.
+#[derive(Clone, Debug)]
+pub struct Record {
+    pub year: u32,
+    pub day: u32,
+    pub timings: Timings,
+}
+
+/// A day is reported as a regression if it got this much slower...
+const REGRESSION_RATIO: f64 = 1.1;
+/// ...and at least this much slower, so noise in fast days is ignored.
+const REGRESSION_MIN: Duration = Duration::from_millis(1);
+
+fn measure<T>(settings: Settings, mut f: impl FnMut() -> T) -> Duration {
+    for _ in 0..settings.warmup {
+        black_box(f());
+    }
+
+    let mut times: Vec<Duration> = (0..settings.runs.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+    times.sort();
+    times[times.len() / 2]
+}
+
+/// Time parsing the input and each part of a solution separately.
+pub fn time<S: Solution>(input: &str, settings: Settings) -> Timings {
+    let parse = measure(settings, || S::parse(input));
+
+    let parsed = S::parse(input);
+    let parts = (1..=S::PARTS)
+        .map(|part| {
+            measure(settings, || match part {
+                1 => S::part1(&parsed),
+                _ => S::part2(&parsed),
+            })
+        })
+        .collect();
+
+    Timings { parse, parts }
+}
+
+fn format_duration(d: Duration) -> String {
+    let ns = d.as_nanos();
+    if ns < 1_000 {
+        format!("{} ns", ns)
+    } else if ns < 1_000_000 {
+        format!("{:.1} µs", ns as f64 / 1e3)
+    } else if ns < 1_000_000_000 {
+        format!("{:.1} ms", ns as f64 / 1e6)
+    } else {
+        format!("{:.2} s", ns as f64 / 1e9)
+    }
+}
+
+fn is_regression(old: Duration, new: Duration) -> bool {
+    new.as_secs_f64() > old.as_secs_f64() * REGRESSION_RATIO && new > old + REGRESSION_MIN
+}
+
+/// The change from an old time to a new one in percent, or `-` if the old
+/// time is zero.
+fn format_change(old: Duration, new: Duration) -> String {
+    if old.is_zero() {
+        return "-".to_string();
+    }
+    format!("{:+.0}%", (new.as_secs_f64() / old.as_secs_f64() - 1.0) * 100.0)
+}
+
+/// Render a table of the records, slowest first. With a baseline, the
+/// table shows the change in total time for each day.
+pub fn table(records: &[Record], baseline: Option<&Baseline>) -> String {
+    let mut records: Vec<&Record> = records.iter().collect();
+    records.sort_by_key(|r| Reverse(r.timings.total()));
+
+    let mut out = format!(
+        "{:<12} {:>10} {:>10} {:>10} {:>10}",
+        "day", "parse", "part 1", "part 2", "total",
+    );
+    if baseline.is_some() {
+        write!(out, " {:>10} {:>8}", "baseline", "change").unwrap();
+    }
+    out.push('\n');
+
+    for record in records {
+        let t = &record.timings;
+        let part = |i: usize| t.parts.get(i).map_or_else(|| "-".to_string(), |&d| format_duration(d));
+        write!(
+            out,
+            "{:<12} {:>10} {:>10} {:>10} {:>10}",
+            format!("{} day {}", record.year, record.day),
+            format_duration(t.parse),
+            part(0),
+            part(1),
+            format_duration(t.total()),
+        )
+        .unwrap();
+
+        if let Some(baseline) = baseline {
+            match baseline.get(record.year, record.day) {
+                Some(old) => {
+                    let old = old.total();
+                    let flag = if is_regression(old, t.total()) { " !" } else { "" };
+                    let change = format_change(old, t.total());
+                    write!(out, " {:>10} {:>8}{}", format_duration(old), change, flag).unwrap();
+                }
+                None => write!(out, " {:>10} {:>8}", "-", "-").unwrap(),
+            }
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Timings saved by an earlier run.
+#[derive(Default, Debug)]
+pub struct Baseline {
+    timings: HashMap<(u32, u32), Timings>,
+}
+
+impl Baseline {
+    pub fn get(&self, year: u32, day: u32) -> Option<&Timings> {
+        self.timings.get(&(year, day))
+    }
+
+    pub fn load(path: &str) -> Result<Self, String> {
+        let text = fs::read_to_string(path).map_err(|e| format!("{}: {}", path, e))?;
+        Self::parse(&text).map_err(|e| format!("{}: {}", path, e))
+    }
+
+    fn parse(text: &str) -> Result<Self, String> {
+        let mut timings = HashMap::new();
+        for (i, line) in text.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let fields: Vec<u64> = line
+                .split_whitespace()
+                .map(|n| n.parse().map_err(|_| format!("line {}: invalid number: {}", i + 1, n)))
+                .collect::<Result<_, _>>()?;
+            if fields.len() < 4 {
+                return Err(format!("line {}: expected a year, a day and at least two times", i + 1));
+            }
+
+            let nanos: Vec<Duration> = fields[2..].iter().map(|&ns| Duration::from_nanos(ns)).collect();
+            let year_day = (fields[0] as u32, fields[1] as u32);
+            timings.insert(year_day, Timings { parse: nanos[0], parts: nanos[1..].to_vec() });
+        }
+        Ok(Self { timings })
+    }
+
+    /// The records as the contents of a baseline file.
+    pub fn format(records: &[Record]) -> String {
+        let mut out = String::new();
+        for record in records {
+            write!(out, "{} {} {}", record.year, record.day, record.timings.parse.as_nanos()).unwrap();
+            for part in &record.timings.parts {
+                write!(out, " {}", part.as_nanos()).unwrap();
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    /// The days that got noticeably slower than in the baseline.
+    pub fn regressions<'r>(&self, records: &'r [Record]) -> Vec<&'r Record> {
+        records
+            .iter()
+            .filter(|r| {
+                self.get(r.year, r.day)
+                    .is_some_and(|old| is_regression(old.total(), r.timings.total()))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(year: u32, day: u32, ms: &[u64]) -> Record {
+        let ms: Vec<Duration> = ms.iter().map(|&ms| Duration::from_millis(ms)).collect();
+        Record { year, day, timings: Timings { parse: ms[0], parts: ms[1..].to_vec() } }
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(512)), "512 ns");
+        assert_eq!(format_duration(Duration::from_nanos(48_200)), "48.2 µs");
+        assert_eq!(format_duration(Duration::from_micros(1_503)), "1.5 ms");
+        assert_eq!(format_duration(Duration::from_millis(2_250)), "2.25 s");
+    }
+
+    #[test]
+    fn test_format_change() {
+        let ms = Duration::from_millis;
+        assert_eq!(format_change(ms(100), ms(150)), "+50%");
+        assert_eq!(format_change(ms(100), ms(75)), "-25%");
+        assert_eq!(format_change(ms(100), ms(100)), "+0%");
+        assert_eq!(format_change(Duration::ZERO, ms(5)), "-");
+    }
+
+    #[test]
+    fn test_baseline() {
+        let records = [record(2015, 9, &[1, 20, 30]), record(2019, 16, &[2, 40])];
+        let text = Baseline::format(&records);
+        assert_eq!(text, "2015 9 1000000 20000000 30000000\n2019 16 2000000 40000000\n");
+
+        let baseline = Baseline::parse(&text).unwrap();
+        assert_eq!(baseline.get(2015, 9), Some(&records[0].timings));
+        assert_eq!(baseline.get(2019, 16), Some(&records[1].timings));
+        assert!(Baseline::parse("2015 9 x 1").is_err());
+    }
+
+    #[test]
+    fn test_regressions() {
+        let baseline = Baseline::parse(&Baseline::format(&[
+            record(2015, 1, &[0, 100, 100]),
+            record(2015, 2, &[0, 100, 100]),
+            record(2015, 3, &[0, 0, 0]),
+        ]))
+        .unwrap();
+
+        let records = [
+            record(2015, 1, &[0, 150, 100]),
+            record(2015, 2, &[0, 105, 100]),
+            record(2015, 3, &[0, 0, 0]),
+            record(2015, 4, &[0, 900, 900]),
+        ];
+        let regressions: Vec<u32> = baseline.regressions(&records).iter().map(|r| r.day).collect();
+        assert_eq!(regressions, [1]);
+    }
+}
@@ -0,0 +1,136 @@
+//! The registry of all solutions known to the runner.
+
+use std::path::{Path, PathBuf};
+
+use aoc::input::{Lookup, Source};
+use aoc::{Answer, Solution};
+
+use crate::bench::{self, Settings, Timings};
+
+/// The root of the repository.
+pub fn root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+}
+
+/// A solution together with the information needed to run it.
+pub struct Day {
+    pub year: u32,
+    pub day: u32,
+    pub parts: u32,
+    /// The crate directory, relative to the repository root.
+    pub dir: &'static str,
+    /// The embedded puzzle input, if the day has one.
+    pub input: Option<&'static str>,
+    solve: fn(&str, u32) -> Answer,
+    time: fn(&str, Settings) -> Timings,
+}
+
+impl Day {
+    fn new<S: Solution>(dir: &'static str) -> Self {
+        Self {
+            year: S::YEAR,
+            day: S::DAY,
+            parts: S::PARTS,
+            dir,
+            input: S::INPUT,
+            solve: aoc::solve::<S>,
+            time: bench::time::<S>,
+        }
+    }
+
+    /// The path of the day's crate directory.
+    pub fn path(&self) -> PathBuf {
+        root().join(self.dir)
+    }
+
+    /// Where to look for the day's input: only the given source, or the
+    /// default places.
+    pub fn lookup(&self, source: Option<Source>) -> Lookup {
+        match source {
+            Some(source) => Lookup::explicit(self.year, self.day, source),
+            None => Lookup::new(self.year, self.day, &self.path(), self.input),
+        }
+    }
+
+    pub fn solve(&self, input: &str, part: u32) -> Answer {
+        (self.solve)(input, part)
+    }
+
+    pub fn time(&self, input: &str, settings: Settings) -> Timings {
+        (self.time)(input, settings)
+    }
+}
+
+/// Every solved day, ordered by year and day.
+pub fn all() -> Vec<Day> {
+    vec![
+        Day::new::<aoc2015_day01::Solver>("2015/day01"),
+        Day::new::<aoc2015_day02::Solver>("2015/day02"),
+        Day::new::<aoc2015_day03::Solver>("2015/day03"),
+        Day::new::<aoc2015_day04::Solver>("2015/day04"),
+        Day::new::<aoc2015_day05::Solver>("2015/day05"),
+        Day::new::<aoc2015_day06::Solver>("2015/day06"),
+        Day::new::<aoc2015_day07::Solver>("2015/day07"),
+        Day::new::<aoc2015_day08::Solver>("2015/day08"),
+        Day::new::<aoc2015_day09::Solver>("2015/day09"),
+        Day::new::<aoc2015_day10::Solver>("2015/day10"),
+        Day::new::<aoc2015_day11::Solver>("2015/day11"),
+        Day::new::<aoc2015_day12::Solver>("2015/day12"),
+        Day::new::<aoc2015_day13::Solver>("2015/day13"),
+        Day::new::<aoc2015_day14::Solver>("2015/day14"),
+        Day::new::<aoc2015_day15::Solver>("2015/day15"),
+        Day::new::<aoc2015_day16::Solver>("2015/day16"),
+        Day::new::<aoc2015_day17::Solver>("2015/day17"),
+        Day::new::<aoc2015_day18::Solver>("2015/day18"),
+        Day::new::<aoc2018_day1::Solver>("2018/day1"),
+        Day::new::<aoc2018_day2::Solver>("2018/day2"),
+        Day::new::<aoc2018_day3::Solver>("2018/day3"),
+        Day::new::<aoc2019_day01::Solver>("2019/day01"),
+        Day::new::<aoc2019_day02::Solver>("2019/day02"),
+        Day::new::<aoc2019_day03::Solver>("2019/day03"),
+        Day::new::<aoc2019_day04::Solver>("2019/day04"),
+        Day::new::<aoc2019_day05::Solver>("2019/day05"),
+        Day::new::<aoc2019_day06::Solver>("2019/day06"),
+        Day::new::<aoc2019_day07::Solver>("2019/day07"),
+        Day::new::<aoc2019_day08::Solver>("2019/day08"),
+        Day::new::<aoc2019_day09::Solver>("2019/day09"),
+        Day::new::<aoc2019_day10::Solver>("2019/day10"),
+        Day::new::<aoc2019_day11::Solver>("2019/day11"),
+        Day::new::<aoc2019_day12::Solver>("2019/day12"),
+        Day::new::<aoc2019_day13::Solver>("2019/day13"),
+        Day::new::<aoc2019_day16::Solver>("2019/day16"),
+        Day::new::<aoc2019_day17::Solver>("2019/day17"),
+        Day::new::<aoc2019_day19::Solver>("2019/day19"),
+        Day::new::<aoc2019_day20::Solver>("2019/day20"),
+        Day::new::<aoc2020_day01::Solver>("2020/day01"),
+        Day::new::<aoc2020_day02::Solver>("2020/day02"),
+        Day::new::<aoc2020_day03::Solver>("2020/day03"),
+        Day::new::<aoc2020_day04::Solver>("2020/day04"),
+        Day::new::<aoc2020_day05::Solver>("2020/day05"),
+        Day::new::<aoc2020_day06::Solver>("2020/day06"),
+        Day::new::<aoc2020_day07::Solver>("2020/day07"),
+        Day::new::<aoc2020_day08::Solver>("2020/day08"),
+        Day::new::<aoc2020_day09::Solver>("2020/day09"),
+        Day::new::<aoc2020_day10::Solver>("2020/day10"),
+        Day::new::<aoc2020_day11::Solver>("2020/day11"),
+        Day::new::<aoc2020_day12::Solver>("2020/day12"),
+        Day::new::<aoc2020_day13::Solver>("2020/day13"),
+        Day::new::<aoc2020_day14::Solver>("2020/day14"),
+        Day::new::<aoc2020_day15::Solver>("2020/day15"),
+        Day::new::<aoc2020_day16::Solver>("2020/day16"),
+        Day::new::<aoc2020_day17::Solver>("2020/day17"),
+        Day::new::<aoc2020_day18::Solver>("2020/day18"),
+        Day::new::<aoc2020_day21::Solver>("2020/day21"),
+        Day::new::<aoc2021_day01::Solver>("2021/day01"),
+        Day::new::<aoc2021_day02::Solver>("2021/day02"),
+        Day::new::<aoc2021_day03::Solver>("2021/day03"),
+        Day::new::<aoc2021_day04::Solver>("2021/day04"),
+        Day::new::<aoc2021_day05::Solver>("2021/day05"),
+        Day::new::<aoc2021_day06::Solver>("2021/day06"),
+    ]
+}
+
+/// Find the solution for the given year and day.
+pub fn find(year: u32, day: u32) -> Option<Day> {
+    all().into_iter().find(|d| d.year == year && d.day == day)
+}
@@ -0,0 +1,55 @@
+//! Checks every solution against the answers recorded in `answers.json`, so
+//! a refactor cannot silently change them.
+
+use crate::answers::Answers;
+use crate::days::{self, Day};
+use crate::read_input;
+use crate::report;
+
+/// Run every part of a day and describe each answer that differs from the
+/// recorded one.
+fn check(day: &Day, recorded: &[String]) -> Vec<String> {
+    let header = |part| format!("{} day {} part {}", day.year, day.day, part);
+
+    let input = match read_input(day, None) {
+        Ok(input) => input,
+        Err(e) => return vec![e],
+    };
+    if recorded.len() != day.parts as usize {
+        return vec![format!(
+            "{} day {}: {} answer(s) recorded for {} part(s)",
+            day.year, day.day, recorded.len(), day.parts,
+        )];
+    }
+
+    let mut failures = Vec::new();
+    for (part, expected) in (1..).zip(recorded) {
+        match report::solve(day, &input, part).0 {
+            Some(answer) if answer.to_string() == *expected => {}
+            Some(answer) => failures.push(format!(
+                "{}: expected {:?}, got {:?}",
+                header(part), expected, answer.to_string(),
+            )),
+            None => failures.push(format!("{}: panicked", header(part))),
+        }
+    }
+    failures
+}
+
+#[test]
+fn test_known_answers() {
+    let answers = Answers::load().unwrap();
+
+    let mut failures = Vec::new();
+    for day in days::all() {
+        match answers.get(day.year, day.day) {
+            Some(recorded) if recorded.is_empty() => {}
+            Some(recorded) => failures.extend(check(&day, &recorded)),
+            // a day without an input file has nothing to check
+            None if read_input(&day, None).is_err() => {}
+            None => failures.push(format!("{} day {}: no recorded answers", day.year, day.day)),
+        }
+    }
+
+    assert!(failures.is_empty(), "{} mismatch(es):\n{}", failures.len(), failures.join("\n"));
+}
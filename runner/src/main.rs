@@ -0,0 +1,373 @@
+//! Runs any of the solutions in this repository.
+//!
+//! ```text
+//! aoc run <year> <day> [--part N] [--input PATH] [--format FORMAT]
+//! aoc run --all [--format FORMAT]
+//! aoc bench [<year> <day> [--input PATH] | --all] [--warmup N] [--runs N] [--save PATH] [--compare PATH]
+//! aoc new <year> <day>
+//! aoc import <dir> [--force]
+//! ```
+//!
+//! `--input -` reads the input from stdin. Without `--input`, the input is
+//! taken from `$AOC_INPUT_DIR/<year>/day<DD>.txt`, the day's `input` file or
+//! the input embedded in the solution, whichever is found first.
+//!
+//! `--format` is `text`, `json` or `csv`. JSON and CSV print a record per
+//! part with its status against `answers.json`, as described in [`report`].
+//! A part that panics is reported as such and the run goes on.
+//!
+//! `bench` times every day, or the given one, and prints a table of the
+//! slowest days. `--save` writes the timings to a baseline file, and
+//! `--compare` shows the change against one and fails on regressions.
+//!
+//! `new` creates the crate for a new day from the standard skeleton and
+//! registers it with the workspace, the runner and `answers.json`.
+//!
+//! `import` copies downloaded inputs into the input store and records their
+//! hashes in `inputs.json`, as described in [`store`]. Inputs found in the
+//! default places are checked against the recorded hashes before use.
+
+use std::env;
+use std::fs;
+use std::process;
+
+mod answers;
+mod bench;
+mod days;
+#[cfg(test)]
+mod known_answers;
+mod report;
+mod scaffold;
+mod store;
+
+use answers::Answers;
+use aoc::input::Source;
+use bench::{Baseline, Record, Settings};
+use days::Day;
+use report::{Format, Outcome, Status};
+use store::Hashes;
+
+const USAGE: &str = "\
+Usage:
+    aoc run <year> <day> [--part N] [--input PATH] [--format text|json|csv]
+    aoc run --all [--format text|json|csv]
+    aoc bench [<year> <day> [--input PATH] | --all] [--warmup N] [--runs N] [--save PATH] [--compare PATH]
+    aoc new <year> <day>
+    aoc import <dir> [--force]";
+
+/// What to run, as given on the command line.
+enum Command {
+    Run {
+        year: u32,
+        day: u32,
+        part: Option<u32>,
+        input: Option<String>,
+        format: Format,
+    },
+    RunAll {
+        format: Format,
+    },
+    Bench {
+        day: Option<(u32, u32)>,
+        input: Option<String>,
+        settings: Settings,
+        save: Option<String>,
+        compare: Option<String>,
+    },
+    New {
+        year: u32,
+        day: u32,
+    },
+    Import {
+        dir: String,
+        force: bool,
+    },
+}
+
+fn parse_number(value: &str, what: &str) -> Result<u32, String> {
+    value.parse().map_err(|_| format!("Invalid {}: {}", what, value))
+}
+
+fn parse_args(args: &[String]) -> Result<Command, String> {
+    let command = match args.first().map(String::as_str) {
+        Some("new") => {
+            return match &args[1..] {
+                [year, day] => Ok(Command::New {
+                    year: parse_number(year, "year")?,
+                    day: parse_number(day, "day")?,
+                }),
+                _ => Err("Expected a year and a day".to_string()),
+            };
+        }
+        Some("import") => {
+            return match &args[1..] {
+                [dir] => Ok(Command::Import { dir: dir.clone(), force: false }),
+                [dir, force] | [force, dir] if force == "--force" => {
+                    Ok(Command::Import { dir: dir.clone(), force: true })
+                }
+                _ => Err("Expected a directory".to_string()),
+            };
+        }
+        Some(cmd @ ("run" | "bench")) => cmd,
+        Some(cmd) => return Err(format!("Unknown command: {}", cmd)),
+        None => return Err("Missing command".to_string()),
+    };
+    let bench = command == "bench";
+
+    let mut positional = Vec::new();
+    let mut part = None;
+    let mut input = None;
+    let mut all = false;
+    let mut format = Format::Text;
+    let mut settings = Settings::default();
+    let mut save = None;
+    let mut compare = None;
+
+    let mut args = args[1..].iter();
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("Missing value for {}", arg));
+        match arg.as_str() {
+            "--all" => all = true,
+            "--part" if !bench => part = Some(parse_number(value()?, "part")?),
+            "--input" => input = Some(value()?.clone()),
+            "--format" if !bench => format = Format::parse(value()?)?,
+            "--warmup" if bench => settings.warmup = parse_number(value()?, "warmup")?,
+            "--runs" if bench => settings.runs = parse_number(value()?, "number of runs")?,
+            "--save" if bench => save = Some(value()?.clone()),
+            "--compare" if bench => compare = Some(value()?.clone()),
+            _ if arg.starts_with("--") => return Err(format!("Unknown option: {}", arg)),
+            _ => positional.push(arg),
+        }
+    }
+
+    let day = match positional[..] {
+        [] => None,
+        [year, day] => Some((parse_number(year, "year")?, parse_number(day, "day")?)),
+        _ => return Err("Expected a year and a day".to_string()),
+    };
+
+    if bench {
+        if all && day.is_some() {
+            return Err("--all cannot be combined with a day".to_string());
+        }
+        if day.is_none() && input.is_some() {
+            return Err("--input needs a year and a day".to_string());
+        }
+        return Ok(Command::Bench { day, input, settings, save, compare });
+    }
+
+    if all {
+        if day.is_some() || part.is_some() || input.is_some() {
+            return Err("--all cannot be combined with a day, --part or --input".to_string());
+        }
+        return Ok(Command::RunAll { format });
+    }
+
+    match day {
+        Some((year, day)) => Ok(Command::Run { year, day, part, input, format }),
+        None => Err("Expected a year and a day".to_string()),
+    }
+}
+
+/// Read the input for a day from the given path, `-` meaning stdin, or
+/// from the first of the default places that has it. An input from the
+/// default places has to match its recorded hash.
+fn read_input(day: &Day, path: Option<&str>) -> Result<String, String> {
+    let lookup = day.lookup(path.map(Source::from_arg));
+    let (source, input) = lookup.read_with_source().map_err(|e| e.to_string())?;
+    if path.is_none() {
+        Hashes::load()?
+            .verify(day.year, day.day, &input)
+            .map_err(|e| format!("{}: {}", source, e))?;
+    }
+    Ok(input)
+}
+
+/// Run the given part of a day, or every part, and print the outcomes.
+/// Returns whether no part panicked or gave a wrong answer.
+fn run(
+    day: &Day,
+    part: Option<u32>,
+    input: Option<&str>,
+    format: Format,
+    answers: Option<&Answers>,
+) -> Result<bool, String> {
+    let parts = match part {
+        Some(part) if part == 0 || part > day.parts => {
+            return Err(format!("{} day {} has no part {}", day.year, day.day, part));
+        }
+        Some(part) => part..=part,
+        None => 1..=day.parts,
+    };
+
+    let input = read_input(day, input)?;
+    let input_hash = aoc::input::hash(&input);
+    let recorded = answers.and_then(|answers| answers.get(day.year, day.day)).unwrap_or_default();
+
+    let mut ok = true;
+    for part in parts {
+        let (answer, elapsed) = report::solve(day, &input, part);
+        let outcome = Outcome {
+            year: day.year,
+            day: day.day,
+            part,
+            answer,
+            expected: recorded.get(part as usize - 1).cloned(),
+            elapsed,
+            input_hash: input_hash.clone(),
+        };
+        ok &= !matches!(outcome.status(), Status::Panicked | Status::WrongAnswer);
+        println!("{}", outcome.format(format));
+    }
+    Ok(ok)
+}
+
+/// Load the recorded answers if the format reports on them, and print the
+/// header of the format.
+fn start_report(format: Format) -> Option<Answers> {
+    let answers = (format != Format::Text).then(|| {
+        Answers::load().unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            process::exit(1);
+        })
+    });
+    if let Some(header) = format.header() {
+        println!("{}", header);
+    }
+    answers
+}
+
+/// Time the given days and print the table, comparing against and saving
+/// baselines as requested. Returns whether every day could be timed and
+/// none of them regressed.
+fn bench(
+    days: &[Day],
+    input: Option<&str>,
+    settings: Settings,
+    save: Option<&str>,
+    compare: Option<&str>,
+) -> Result<bool, String> {
+    let baseline = compare.map(Baseline::load).transpose()?;
+
+    let mut ok = true;
+    let mut records = Vec::new();
+    for day in days {
+        match read_input(day, input) {
+            Ok(input) => records.push(Record {
+                year: day.year,
+                day: day.day,
+                timings: day.time(&input, settings),
+            }),
+            Err(e) => {
+                eprintln!("{}", e);
+                ok = false;
+            }
+        }
+    }
+
+    print!("{}", bench::table(&records, baseline.as_ref()));
+
+    if let Some(path) = save {
+        fs::write(path, Baseline::format(&records)).map_err(|e| format!("{}: {}", path, e))?;
+    }
+    if let Some(baseline) = baseline {
+        let regressions = baseline.regressions(&records);
+        if !regressions.is_empty() {
+            eprintln!("{} day(s) slower than the baseline:", regressions.len());
+            for record in regressions {
+                eprintln!("    {} day {}", record.year, record.day);
+            }
+            ok = false;
+        }
+    }
+    Ok(ok)
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let command = parse_args(&args).unwrap_or_else(|e| {
+        eprintln!("{}\n\n{}", e, USAGE);
+        process::exit(2);
+    });
+
+    match command {
+        Command::Run { year, day, part, input, format } => {
+            let day = days::find(year, day).unwrap_or_else(|| {
+                eprintln!("No solution for {} day {}", year, day);
+                process::exit(1);
+            });
+            let answers = start_report(format);
+            match run(&day, part, input.as_deref(), format, answers.as_ref()) {
+                Ok(true) => {}
+                Ok(false) => process::exit(1),
+                Err(e) => {
+                    eprintln!("{}", e);
+                    process::exit(1);
+                }
+            }
+        }
+        Command::RunAll { format } => {
+            let answers = start_report(format);
+            let mut failed = false;
+            for day in days::all() {
+                match run(&day, None, None, format, answers.as_ref()) {
+                    Ok(ok) => failed |= !ok,
+                    Err(e) => {
+                        eprintln!("{}", e);
+                        failed = true;
+                    }
+                }
+            }
+            if failed {
+                process::exit(1);
+            }
+        }
+        Command::Bench { day, input, settings, save, compare } => {
+            let days = match day {
+                Some((year, day)) => match days::find(year, day) {
+                    Some(day) => vec![day],
+                    None => {
+                        eprintln!("No solution for {} day {}", year, day);
+                        process::exit(1);
+                    }
+                },
+                None => days::all(),
+            };
+            match bench(&days, input.as_deref(), settings, save.as_deref(), compare.as_deref()) {
+                Ok(true) => {}
+                Ok(false) => process::exit(1),
+                Err(e) => {
+                    eprintln!("{}", e);
+                    process::exit(1);
+                }
+            }
+        }
+        Command::New { year, day } => match scaffold::new_day(days::root(), year, day) {
+            Ok(dir) => {
+                println!("Created {}", dir.display());
+                println!("Put the puzzle input in {}", aoc::input::store_path(year, day).display());
+            }
+            Err(e) => {
+                eprintln!("{}", e);
+                process::exit(1);
+            }
+        },
+        Command::Import { dir, force } => match store::import(dir.as_ref(), force) {
+            Ok(import) => {
+                for (year, day, path) in &import.imported {
+                    println!("Imported {} day {} from {}", year, day, path.display());
+                }
+                for (path, reason) in &import.skipped {
+                    eprintln!("Skipped {}: {}", path.display(), reason);
+                }
+                if !import.skipped.is_empty() {
+                    process::exit(1);
+                }
+            }
+            Err(e) => {
+                eprintln!("{}", e);
+                process::exit(1);
+            }
+        },
+    }
+}
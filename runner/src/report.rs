@@ -0,0 +1,196 @@
+//! Running single parts with their panics caught, and reporting the results
+//! as text for people or as JSON or CSV records for tools.
+//!
+//! JSON output has an object per line; CSV output starts with a header. Both
+//! have these fields for every part:
+//!
+//! - `year`, `day`, `part`;
+//! - `status`: `ok` or `wrong-answer` compared with `answers.json`,
+//!   `unchecked` if no answer is recorded, or `panicked`;
+//! - `answer`, missing if the part panicked, and `expected`;
+//! - `elapsed_ns`: the time of parsing the input and solving the part;
+//! - `input_hash`: the FNV-1a hash of the input, in hex.
+
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+use serde_json::json;
+
+use aoc::Answer;
+
+use crate::days::Day;
+
+/// How results are printed.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl Format {
+    pub fn parse(name: &str) -> Result<Self, String> {
+        match name {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("Unknown format: {}", name)),
+        }
+    }
+
+    /// The line to print before any records.
+    pub fn header(self) -> Option<&'static str> {
+        match self {
+            Format::Csv => Some("year,day,part,status,answer,expected,elapsed_ns,input_hash"),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Status {
+    Ok,
+    WrongAnswer,
+    Unchecked,
+    Panicked,
+}
+
+impl Status {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Status::Ok => "ok",
+            Status::WrongAnswer => "wrong-answer",
+            Status::Unchecked => "unchecked",
+            Status::Panicked => "panicked",
+        }
+    }
+}
+
+/// The result of running one part.
+#[derive(Clone, Debug)]
+pub struct Outcome {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    /// The answer, or `None` if the part panicked.
+    pub answer: Option<Answer>,
+    /// The recorded answer, as it would be displayed.
+    pub expected: Option<String>,
+    pub elapsed: Duration,
+    pub input_hash: String,
+}
+
+impl Outcome {
+    pub fn status(&self) -> Status {
+        match (&self.answer, &self.expected) {
+            (None, _) => Status::Panicked,
+            (Some(_), None) => Status::Unchecked,
+            (Some(answer), Some(expected)) if answer.to_string() == *expected => Status::Ok,
+            (Some(_), Some(_)) => Status::WrongAnswer,
+        }
+    }
+
+    /// The outcome as a line, or several for letter art in text.
+    pub fn format(&self, format: Format) -> String {
+        let answer = self.answer.as_ref().map(Answer::to_string);
+        match format {
+            Format::Text => {
+                let header = format!("{} day {} part {}", self.year, self.day, self.part);
+                match &self.answer {
+                    Some(answer @ Answer::Grid(_)) => format!("{}:\n{}", header, answer),
+                    Some(answer) => format!("{}: {}", header, answer),
+                    None => format!("{}: panicked", header),
+                }
+            }
+            Format::Json => json!({
+                "year": self.year,
+                "day": self.day,
+                "part": self.part,
+                "status": self.status().as_str(),
+                "answer": answer,
+                "expected": self.expected,
+                "elapsed_ns": self.elapsed.as_nanos() as u64,
+                "input_hash": self.input_hash,
+            })
+            .to_string(),
+            Format::Csv => [
+                self.year.to_string(),
+                self.day.to_string(),
+                self.part.to_string(),
+                self.status().as_str().to_string(),
+                csv_field(answer.as_deref().unwrap_or_default()),
+                csv_field(self.expected.as_deref().unwrap_or_default()),
+                self.elapsed.as_nanos().to_string(),
+                self.input_hash.clone(),
+            ]
+            .join(","),
+        }
+    }
+}
+
+/// Quote a CSV field if it needs to be.
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+/// Solve one part, catching a panic instead of letting it end the run.
+pub fn solve(day: &Day, input: &str, part: u32) -> (Option<Answer>, Duration) {
+    let start = Instant::now();
+    let answer = panic::catch_unwind(AssertUnwindSafe(|| day.solve(input, part))).ok();
+    (answer, start.elapsed())
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::input::hash;
+
+    use super::*;
+
+    fn outcome(answer: Option<Answer>, expected: Option<&str>) -> Outcome {
+        Outcome {
+            year: 2015,
+            day: 11,
+            part: 1,
+            answer,
+            expected: expected.map(str::to_string),
+            elapsed: Duration::from_micros(1500),
+            input_hash: hash("hxbxwxba"),
+        }
+    }
+
+    #[test]
+    fn test_status() {
+        let answer = || Some(Answer::from("hxbxxyzz"));
+        assert_eq!(outcome(answer(), Some("hxbxxyzz")).status(), Status::Ok);
+        assert_eq!(outcome(answer(), Some("hxcaabcc")).status(), Status::WrongAnswer);
+        assert_eq!(outcome(answer(), None).status(), Status::Unchecked);
+        assert_eq!(outcome(None, Some("hxbxxyzz")).status(), Status::Panicked);
+    }
+
+    #[test]
+    fn test_format() {
+        let ok = outcome(Some(Answer::from("hxbxxyzz")), Some("hxbxxyzz"));
+        let hash = hash("hxbxwxba");
+        assert_eq!(ok.format(Format::Text), "2015 day 11 part 1: hxbxxyzz");
+        assert_eq!(
+            ok.format(Format::Json),
+            format!(
+                r#"{{"answer":"hxbxxyzz","day":11,"elapsed_ns":1500000,"expected":"hxbxxyzz","input_hash":"{}","part":1,"status":"ok","year":2015}}"#,
+                hash,
+            ),
+        );
+        assert_eq!(ok.format(Format::Csv), format!("2015,11,1,ok,hxbxxyzz,hxbxxyzz,1500000,{}", hash));
+
+        let panicked = outcome(None, None);
+        assert_eq!(panicked.format(Format::Text), "2015 day 11 part 1: panicked");
+        assert_eq!(panicked.format(Format::Csv), format!("2015,11,1,panicked,,,1500000,{}", hash));
+
+        let grid = outcome(Some(Answer::Grid("#.\n.#".to_string())), None);
+        assert_eq!(grid.format(Format::Text), "2015 day 11 part 1:\n#.\n.#");
+        assert_eq!(grid.format(Format::Csv), format!("2015,11,1,unchecked,\"#.\n.#\",,1500000,{}", hash));
+    }
+}
@@ -0,0 +1,293 @@
+//! Generating the crate for a new day and registering it everywhere a day
+//! has to be known: the workspace, the runner and `answers.json`.
+
+use std::fs;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+const FIRST_YEAR: u32 = 2015;
+
+/// The files and edits that make up a new day, computed up front so nothing
+/// is written when any of them fails.
+struct Plan {
+    dir: PathBuf,
+    files: Vec<(PathBuf, String)>,
+}
+
+fn crate_name(year: u32, day: u32) -> String {
+    format!("aoc{}-day{:02}", year, day)
+}
+
+fn manifest(year: u32, day: u32) -> String {
+    format!(
+        r#"[package]
+name = "{}"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+aoc = {{ workspace = true }}
+"#,
+        crate_name(year, day),
+    )
+}
+
+fn main_rs(year: u32, day: u32) -> String {
+    format!(
+        "fn main() {{\n    aoc::main::<aoc{}_day{:02}::Solver>();\n}}\n",
+        year, day,
+    )
+}
+
+fn lib_rs(year: u32, day: u32) -> String {
+    format!(
+        r#"use aoc::{{Answer, Solution}};
+
+pub struct Solver;
+
+impl Solution for Solver {{
+    const YEAR: u32 = {};
+    const DAY: u32 = {};
+    const PARTS: u32 = 1;
+
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Self::Input<'_> {{
+        input.lines().collect()
+    }}
+
+    fn part1(input: &Self::Input<'_>) -> Answer {{
+        part1(input).into()
+    }}
+
+    fn part2(_input: &Self::Input<'_>) -> Answer {{
+        Answer::Unsolved
+    }}
+}}
+
+fn part1(lines: &[&str]) -> usize {{
+    lines.len()
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+
+    aoc::examples! {{
+        Solver;
+        // Fill in the example and its answer, then remove the `#[ignore]`
+        #[ignore]
+        example: include_str!("../example") => part1 = 0;
+    }}
+}}
+"#,
+        year, day,
+    )
+}
+
+/// The year and day of a line mentioning a day's crate, like
+/// `aoc2015-day01` or `aoc2015_day01`.
+fn crate_key(line: &str) -> Option<(u32, u32)> {
+    let start = line.find("aoc")? + 3;
+    let rest = &line[start..];
+    let year = rest.get(..4)?.parse().ok()?;
+    let rest = rest[4..].strip_prefix("-day").or_else(|| rest[4..].strip_prefix("_day"))?;
+    let len = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+    Some((year, rest[..len].parse().ok()?))
+}
+
+/// The year of a workspace member line like `"2019/day*",`.
+fn member_key(line: &str) -> Option<(u32, u32)> {
+    let year = line.trim().strip_prefix('"')?.get(..4)?.parse().ok()?;
+    Some((year, 0))
+}
+
+/// Insert a line before the first line with a greater key, or after the
+/// last line that has a key at all.
+fn insert_line(
+    text: &str,
+    line: &str,
+    key: (u32, u32),
+    key_of: fn(&str) -> Option<(u32, u32)>,
+) -> Result<String, String> {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let keyed: Vec<(usize, (u32, u32))> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, l)| Some((i, key_of(l)?)))
+        .collect();
+
+    if keyed.iter().any(|&(_, k)| k == key) {
+        return Err(format!("{} is already registered", line.trim()));
+    }
+    let at = match keyed.iter().find(|&&(_, k)| k > key) {
+        Some(&(i, _)) => i,
+        None => keyed.last().ok_or("no line to insert after")?.0 + 1,
+    };
+    lines.insert(at, line);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// The key of a JSON object member starting on this line, if the line is
+/// indented by exactly `indent`.
+fn member_at(line: &str, indent: &str) -> Option<u32> {
+    let rest = line.strip_prefix(indent)?.strip_prefix('"')?;
+    rest[..rest.find('"')?].parse().ok()
+}
+
+/// Insert a member into the JSON object whose members are on the lines in
+/// `body`, keeping the keys in numeric order and the commas in place.
+fn insert_member(
+    lines: &mut Vec<String>,
+    body: Range<usize>,
+    indent: &str,
+    key: u32,
+    mut member: Vec<String>,
+) -> Result<(), String> {
+    let starts: Vec<(usize, u32)> =
+        body.clone().filter_map(|i| Some((i, member_at(&lines[i], indent)?))).collect();
+
+    if starts.iter().any(|&(_, k)| k == key) {
+        return Err(format!("\"{}\" already exists", key));
+    }
+    let at = match starts.iter().find(|&&(_, k)| k > key) {
+        Some(&(i, _)) => {
+            member.last_mut().unwrap().push(',');
+            i
+        }
+        None => {
+            if !starts.is_empty() {
+                lines[body.end - 1].push(',');
+            }
+            body.end
+        }
+    };
+    lines.splice(at..at, member);
+    Ok(())
+}
+
+/// Add an empty list of answers for the day to `answers.json`.
+fn add_answers(text: &str, year: u32, day: u32) -> Result<String, String> {
+    let mut lines: Vec<String> = text.lines().map(str::to_string).collect();
+    let close = lines.iter().rposition(|l| l == "}").ok_or("answers.json is not an object")?;
+    let entry = format!("    \"{}\": []", day);
+
+    match lines.iter().position(|l| member_at(l, "  ") == Some(year)) {
+        Some(start) => {
+            let end = (start..close)
+                .find(|&i| lines[i] == "  }" || lines[i] == "  },")
+                .ok_or_else(|| format!("year {} is not closed", year))?;
+            insert_member(&mut lines, start + 1..end, "    ", day, vec![entry])?;
+        }
+        None => {
+            let block = vec![format!("  \"{}\": {{", year), entry, "  }".to_string()];
+            insert_member(&mut lines, 1..close, "  ", year, block)?;
+        }
+    }
+    Ok(lines.join("\n") + "\n")
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+fn plan(root: &Path, year: u32, day: u32) -> Result<Plan, String> {
+    if year < FIRST_YEAR {
+        return Err(format!("There is no Advent of Code {}", year));
+    }
+    if !(1..=25).contains(&day) {
+        return Err(format!("There is no day {}", day));
+    }
+
+    let rel = format!("{}/day{:02}", year, day);
+    let dir = root.join(&rel);
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+    let key = (year, day);
+
+    let mut files = vec![
+        (dir.join("Cargo.toml"), manifest(year, day)),
+        (dir.join("src/main.rs"), main_rs(year, day)),
+        (dir.join("src/lib.rs"), lib_rs(year, day)),
+        (dir.join("example"), String::new()),
+    ];
+
+    let path = root.join("Cargo.toml");
+    let workspace = read(&path)?;
+    let member = format!("\"{}/day*\"", year);
+    if !workspace.contains(&member) {
+        let line = format!("    {},", member);
+        files.push((path, insert_line(&workspace, &line, (year, 0), member_key)?));
+    }
+
+    let path = root.join("runner/Cargo.toml");
+    let line = format!("{} = {{ path = \"../{}\" }}", crate_name(year, day), rel);
+    files.push((path.clone(), insert_line(&read(&path)?, &line, key, crate_key)?));
+
+    let path = root.join("runner/src/days.rs");
+    let line = format!("        Day::new::<aoc{}_day{:02}::Solver>(\"{}\"),", year, day, rel);
+    files.push((path.clone(), insert_line(&read(&path)?, &line, key, crate_key)?));
+
+    let path = root.join("answers.json");
+    files.push((path.clone(), add_answers(&read(&path)?, year, day)?));
+
+    Ok(Plan { dir, files })
+}
+
+/// Create the crate for a new day in the repository at `root` and register
+/// it. Returns the directory of the new crate.
+pub fn new_day(root: &Path, year: u32, day: u32) -> Result<PathBuf, String> {
+    let plan = plan(root, year, day)?;
+    fs::create_dir_all(plan.dir.join("src")).map_err(|e| format!("{}: {}", plan.dir.display(), e))?;
+    for (path, contents) in &plan.files {
+        fs::write(path, contents).map_err(|e| format!("{}: {}", path.display(), e))?;
+    }
+    Ok(plan.dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crate_key() {
+        assert_eq!(crate_key("aoc2015-day01 = { path = \"../2015/day01\" }"), Some((2015, 1)));
+        assert_eq!(crate_key("        Day::new::<aoc2018_day3::Solver>(\"2018/day3\"),"), Some((2018, 3)));
+        assert_eq!(crate_key("aoc = { workspace = true }"), None);
+    }
+
+    #[test]
+    fn test_insert_line() {
+        let text = "[dependencies]\naoc = { workspace = true }\naoc2018-day1 = 1\naoc2018-day3 = 3\n";
+        assert_eq!(
+            insert_line(text, "aoc2018-day02 = 2", (2018, 2), crate_key).unwrap(),
+            "[dependencies]\naoc = { workspace = true }\naoc2018-day1 = 1\naoc2018-day02 = 2\naoc2018-day3 = 3\n",
+        );
+        assert!(insert_line(text, "aoc2018-day03 = 3", (2018, 3), crate_key).is_err());
+
+        let members = "members = [\n    \"aoc\",\n    \"2015/day*\",\n    \"2019/day*\",\n    \"2019/intcode\",\n]\n";
+        assert_eq!(
+            insert_line(members, "    \"2022/day*\",", (2022, 0), member_key).unwrap(),
+            "members = [\n    \"aoc\",\n    \"2015/day*\",\n    \"2019/day*\",\n    \"2019/intcode\",\n    \"2022/day*\",\n]\n",
+        );
+    }
+
+    #[test]
+    fn test_add_answers() {
+        let text = "{\n  \"2015\": {\n    \"1\": [138, 1771],\n    \"3\": [\n      2081,\n      2341\n    ]\n  }\n}\n";
+        assert_eq!(
+            add_answers(text, 2015, 2).unwrap(),
+            "{\n  \"2015\": {\n    \"1\": [138, 1771],\n    \"2\": [],\n    \"3\": [\n      2081,\n      2341\n    ]\n  }\n}\n",
+        );
+        assert_eq!(
+            add_answers(text, 2015, 4).unwrap(),
+            "{\n  \"2015\": {\n    \"1\": [138, 1771],\n    \"3\": [\n      2081,\n      2341\n    ],\n    \"4\": []\n  }\n}\n",
+        );
+        assert_eq!(
+            add_answers(text, 2016, 1).unwrap(),
+            "{\n  \"2015\": {\n    \"1\": [138, 1771],\n    \"3\": [\n      2081,\n      2341\n    ]\n  },\n  \"2016\": {\n    \"1\": []\n  }\n}\n",
+        );
+        assert!(add_answers(text, 2015, 3).is_err());
+    }
+}
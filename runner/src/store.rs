@@ -0,0 +1,172 @@
+//! Importing downloaded inputs into the input store, and the content hashes
+//! of the inputs recorded in `inputs.json` at the root of the repository.
+//!
+//! Inputs are personal, so only their hashes are shared. They let a run
+//! notice an input that was swapped for another or got truncated. The file
+//! maps years to days to the hash of the input, as made by
+//! [`aoc::input::hash`].
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use aoc::input;
+
+use crate::days;
+
+/// The recorded hashes of the inputs.
+#[derive(Default, Debug)]
+pub struct Hashes {
+    hashes: BTreeMap<u32, BTreeMap<u32, String>>,
+}
+
+impl Hashes {
+    fn path() -> PathBuf {
+        days::root().join("inputs.json")
+    }
+
+    /// Load the recorded hashes. A missing file records no hashes.
+    pub fn load() -> Result<Self, String> {
+        let path = Self::path();
+        let json = match fs::read_to_string(&path) {
+            Ok(json) => json,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(format!("{}: {}", path.display(), e)),
+        };
+        let hashes = serde_json::from_str(&json).map_err(|e| format!("{}: {}", path.display(), e))?;
+        Ok(Self { hashes })
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = Self::path();
+        let json = serde_json::to_string_pretty(&self.hashes).unwrap() + "\n";
+        fs::write(&path, json).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    pub fn get(&self, year: u32, day: u32) -> Option<&str> {
+        self.hashes.get(&year)?.get(&day).map(String::as_str)
+    }
+
+    fn set(&mut self, year: u32, day: u32, hash: String) {
+        self.hashes.entry(year).or_default().insert(day, hash);
+    }
+
+    /// Check an input against the recorded hash, if there is one.
+    pub fn verify(&self, year: u32, day: u32, input: &str) -> Result<(), String> {
+        match self.get(year, day) {
+            Some(hash) if hash != input::hash(input) => Err(format!(
+                "The input for {} day {} does not match its recorded hash {}; \
+                 it may have been swapped or truncated",
+                year, day, hash,
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// The year and day of a downloaded input, from the first number in its
+/// path that looks like a year and the first one after it that looks like
+/// a day.
+fn year_day(path: &Path) -> Option<(u32, u32)> {
+    let path = path.to_string_lossy();
+    let mut numbers = path
+        .split(|c: char| !c.is_ascii_digit())
+        .filter(|n| !n.is_empty());
+
+    let year = numbers.find(|n| n.len() == 4)?.parse().ok()?;
+    let day = numbers
+        .filter_map(|n| n.parse().ok())
+        .find(|day| (1..=25).contains(day))?;
+    Some((year, day))
+}
+
+fn files(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            self::files(&path, files)?;
+        } else {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// What importing a directory did.
+#[derive(Default, Debug)]
+pub struct Import {
+    pub imported: Vec<(u32, u32, PathBuf)>,
+    /// Files that were not imported, with the reason.
+    pub skipped: Vec<(PathBuf, String)>,
+}
+
+/// Copy the inputs in a directory into the store and record their hashes.
+///
+/// Inputs are files named `input` or ending in `.txt` whose path names the
+/// year and the day, like `2015/day01.txt`, `2015/1.txt` or
+/// `2015/day01/input`. An input that does not match the recorded hash is
+/// skipped, unless `force` is given.
+pub fn import(dir: &Path, force: bool) -> Result<Import, String> {
+    let mut paths = Vec::new();
+    files(dir, &mut paths).map_err(|e| format!("{}: {}", dir.display(), e))?;
+    paths.sort();
+
+    let mut hashes = Hashes::load()?;
+    let mut import = Import::default();
+    for path in paths {
+        let rel = path.strip_prefix(dir).unwrap();
+        let is_input = rel.file_name() == Some("input".as_ref())
+            || rel.extension() == Some("txt".as_ref());
+        if !is_input {
+            import.skipped.push((path, "not named input or *.txt".to_string()));
+            continue;
+        }
+        let Some((year, day)) = year_day(rel) else {
+            import.skipped.push((path, "no year and day in its path".to_string()));
+            continue;
+        };
+
+        let text = fs::read_to_string(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        if !force {
+            if let Err(e) = hashes.verify(year, day, &text) {
+                import.skipped.push((path, e));
+                continue;
+            }
+        }
+
+        let target = input::store_path(year, day);
+        fs::create_dir_all(target.parent().unwrap())
+            .and_then(|()| fs::write(&target, &text))
+            .map_err(|e| format!("{}: {}", target.display(), e))?;
+        hashes.set(year, day, input::hash(&text));
+        import.imported.push((year, day, path));
+    }
+
+    hashes.save()?;
+    Ok(import)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_year_day() {
+        assert_eq!(year_day(Path::new("2015/day01.txt")), Some((2015, 1)));
+        assert_eq!(year_day(Path::new("2019/day16/input")), Some((2019, 16)));
+        assert_eq!(year_day(Path::new("aoc-2020-7.txt")), Some((2020, 7)));
+        assert_eq!(year_day(Path::new("2021/day40.txt")), None);
+        assert_eq!(year_day(Path::new("day01.txt")), None);
+    }
+
+    #[test]
+    fn test_verify() {
+        let mut hashes = Hashes::default();
+        assert!(hashes.verify(2015, 1, "(()").is_ok());
+
+        hashes.set(2015, 1, input::hash("(())"));
+        assert!(hashes.verify(2015, 1, "(())").is_ok());
+        assert!(hashes.verify(2015, 1, "(()").is_err());
+    }
+}
@@ -0,0 +1,381 @@
+//! Cellular automata: cells that all change at once by a rule over their
+//! neighbours, step after step.
+//!
+//! There are three kinds:
+//!
+//! - [`Automaton`] for a [`Grid`] of cells, with the neighbourhood and the
+//!   boundary to choose, and cells that can be pinned to a state;
+//! - [`Sparse`] for live cells in an endless space of any number of
+//!   dimensions, which are kept as a set of points;
+//! - [`Population`] for cells that only change by their own state, so they
+//!   can be counted per state instead of kept one by one.
+//!
+//! Every kind keeps a second buffer to write the next generation into, so
+//! a step does not allocate.
+
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+use grid::{Grid, Pos, Step, ADJACENT, ORTHOGONAL};
+
+pub use cycle::Cycle;
+
+/// The cells that count as the neighbours of a cell.
+#[derive(Clone, Copy)]
+pub enum Neighborhood<T> {
+    /// The eight cells around, including the diagonals.
+    Moore,
+    /// The four orthogonal cells.
+    VonNeumann,
+    /// The first cell seen in each of the eight directions, looking past
+    /// the cells for which the function is true.
+    LineOfSight(fn(&T) -> bool),
+}
+
+/// What lies past the edges of the grid.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Boundary {
+    /// Nothing: the cells at the edges have fewer neighbours.
+    Fixed,
+    /// The other side of the grid, as if it wrapped around.
+    Toroidal,
+}
+
+/// An automaton on a grid.
+#[derive(Clone)]
+pub struct Automaton<T> {
+    cells: Grid<T>,
+    next: Grid<T>,
+    neighborhood: Neighborhood<T>,
+    boundary: Boundary,
+    pinned: Vec<(Pos, T)>,
+    generation: usize,
+}
+
+impl<T: Copy + PartialEq> Automaton<T> {
+    pub fn new(cells: Grid<T>, neighborhood: Neighborhood<T>, boundary: Boundary) -> Self {
+        Self { next: cells.clone(), cells, neighborhood, boundary, pinned: Vec::new(), generation: 0 }
+    }
+
+    /// Keep a cell in a state, whatever the rule says.
+    pub fn pin(&mut self, pos: Pos, cell: T) {
+        self.cells[pos] = cell;
+        self.pinned.push((pos, cell));
+    }
+
+    pub fn cells(&self) -> &Grid<T> {
+        &self.cells
+    }
+
+    /// The number of steps taken.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// The number of cells for which the function is true.
+    pub fn count(&self, f: impl Fn(&T) -> bool) -> usize {
+        self.cells.cells().iter().filter(|cell| f(cell)).count()
+    }
+
+    fn offset(&self, (x, y): Pos, (dx, dy): Step) -> Option<Pos> {
+        match self.boundary {
+            Boundary::Fixed => self.cells.offset((x, y), (dx, dy)),
+            Boundary::Toroidal => {
+                let (w, h) = (self.cells.width() as isize, self.cells.height() as isize);
+                let x = (x as isize + dx).rem_euclid(w);
+                let y = (y as isize + dy).rem_euclid(h);
+                Some((x as usize, y as usize))
+            }
+        }
+    }
+
+    fn neighbors(&self, pos: Pos, neighbors: &mut Vec<T>) {
+        let steps: &[Step] = match self.neighborhood {
+            Neighborhood::VonNeumann => &ORTHOGONAL,
+            _ => &ADJACENT,
+        };
+        for &step in steps {
+            let Neighborhood::LineOfSight(see_past) = self.neighborhood else {
+                neighbors.extend(self.offset(pos, step).map(|n| self.cells[n]));
+                continue;
+            };
+
+            // Wrapping around, a ray could go on forever
+            let mut at = pos;
+            for _ in 0..self.cells.width().max(self.cells.height()) {
+                let Some(next) = self.offset(at, step) else { break };
+                at = next;
+                if !see_past(&self.cells[at]) {
+                    neighbors.push(self.cells[at]);
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Apply the rule to every cell at once. The rule gets a cell and its
+    /// neighbours, and gives the next state of the cell. Returns whether any
+    /// cell changed.
+    pub fn step(&mut self, mut rule: impl FnMut(T, &[T]) -> T) -> bool {
+        let mut neighbors = Vec::with_capacity(8);
+        for pos in self.cells.positions() {
+            neighbors.clear();
+            self.neighbors(pos, &mut neighbors);
+            self.next[pos] = rule(self.cells[pos], &neighbors);
+        }
+        for &(pos, cell) in &self.pinned {
+            self.next[pos] = cell;
+        }
+
+        std::mem::swap(&mut self.cells, &mut self.next);
+        self.generation += 1;
+        self.cells != self.next
+    }
+
+    pub fn run(&mut self, steps: usize, mut rule: impl FnMut(T, &[T]) -> T) {
+        for _ in 0..steps {
+            self.step(&mut rule);
+        }
+    }
+
+    /// Step until nothing changes any more, and return the generation in
+    /// which that happened.
+    pub fn run_until_stable(&mut self, mut rule: impl FnMut(T, &[T]) -> T) -> usize {
+        while self.step(&mut rule) {}
+        self.generation - 1
+    }
+
+    /// Step until a generation comes back. The automaton stops at the first
+    /// repeat, at generation `start + len`.
+    pub fn find_cycle(&mut self, mut rule: impl FnMut(T, &[T]) -> T) -> Cycle
+    where
+        T: Eq + Hash,
+    {
+        // The automaton steps its own cells, so the states are copies of them
+        let start = self.generation;
+        let (cycle, _) = cycle::find(self.cells.clone(), |_| {
+            self.step(&mut rule);
+            self.cells.clone()
+        });
+        Cycle { start: start + cycle.start, ..cycle }
+    }
+}
+
+/// An automaton of live cells in an endless space, where every other cell
+/// is dead.
+#[derive(Clone)]
+pub struct Sparse<P, N> {
+    live: HashSet<P>,
+    next: HashSet<P>,
+    counts: HashMap<P, usize>,
+    neighbors: N,
+    generation: usize,
+}
+
+impl<P: Copy + Eq + Hash, N> Sparse<P, N> {
+    /// An automaton with the given live cells. `neighbors` gives the
+    /// neighbours of a point, like `Point3::neighbors` of the geometry
+    /// crate for the 26 cells around a cube.
+    pub fn new(live: impl IntoIterator<Item = P>, neighbors: N) -> Self {
+        Self {
+            live: live.into_iter().collect(),
+            next: HashSet::new(),
+            counts: HashMap::new(),
+            neighbors,
+            generation: 0,
+        }
+    }
+
+    pub fn live(&self) -> &HashSet<P> {
+        &self.live
+    }
+
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// Apply the rule to every cell at once. The rule gets whether a cell
+    /// is alive and how many of its neighbours are, and gives whether it is
+    /// alive next. A dead cell without live neighbours stays dead. Returns
+    /// whether any cell changed.
+    pub fn step<I>(&mut self, mut rule: impl FnMut(bool, usize) -> bool) -> bool
+    where
+        N: FnMut(P) -> I,
+        I: IntoIterator<Item = P>,
+    {
+        self.counts.clear();
+        for &cell in &self.live {
+            self.counts.entry(cell).or_insert(0);
+            for neighbor in (self.neighbors)(cell) {
+                *self.counts.entry(neighbor).or_insert(0) += 1;
+            }
+        }
+
+        self.next.clear();
+        for (&cell, &count) in &self.counts {
+            if rule(self.live.contains(&cell), count) {
+                self.next.insert(cell);
+            }
+        }
+
+        std::mem::swap(&mut self.live, &mut self.next);
+        self.generation += 1;
+        self.live != self.next
+    }
+
+    pub fn run<I>(&mut self, steps: usize, mut rule: impl FnMut(bool, usize) -> bool)
+    where
+        N: FnMut(P) -> I,
+        I: IntoIterator<Item = P>,
+    {
+        for _ in 0..steps {
+            self.step(&mut rule);
+        }
+    }
+}
+
+/// Cells that only change by their own state, counted per state. A cell
+/// can turn into several, or none.
+#[derive(Clone, Debug)]
+pub struct Population {
+    counts: Vec<u64>,
+    next: Vec<u64>,
+    transitions: Vec<Vec<usize>>,
+}
+
+impl Population {
+    /// An empty population, where a cell in state `s` turns into a cell for
+    /// each state in `transitions[s]` every step.
+    pub fn new(transitions: Vec<Vec<usize>>) -> Self {
+        let states = transitions.len();
+        Self { counts: vec![0; states], next: vec![0; states], transitions }
+    }
+
+    pub fn add(&mut self, state: usize, n: u64) {
+        self.counts[state] += n;
+    }
+
+    pub fn count(&self, state: usize) -> u64 {
+        self.counts[state]
+    }
+
+    pub fn total(&self) -> u64 {
+        self.counts.iter().sum()
+    }
+
+    pub fn step(&mut self) {
+        self.next.fill(0);
+        for (state, &n) in self.counts.iter().enumerate() {
+            for &next in &self.transitions[state] {
+                self.next[next] += n;
+            }
+        }
+        std::mem::swap(&mut self.counts, &mut self.next);
+    }
+
+    pub fn run(&mut self, steps: usize) {
+        for _ in 0..steps {
+            self.step();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use geometry::Point3;
+
+    use super::*;
+
+    fn life(s: &str) -> Grid<bool> {
+        Grid::parse(s, |c| Some(c == '#')).unwrap()
+    }
+
+    fn conway(alive: bool, neighbors: &[bool]) -> bool {
+        let n = neighbors.iter().filter(|&&on| on).count();
+        n == 3 || (alive && n == 2)
+    }
+
+    #[test]
+    fn test_stable() {
+        let mut block = Automaton::new(life("....\n.##.\n.##.\n....\n"), Neighborhood::Moore, Boundary::Fixed);
+        assert!(!block.step(conway));
+        assert_eq!(block.run_until_stable(conway), 1);
+
+        let mut dying = Automaton::new(life(".....\n.#...\n...#.\n.....\n"), Neighborhood::Moore, Boundary::Fixed);
+        assert_eq!(dying.run_until_stable(conway), 1);
+        assert_eq!(dying.count(|&on| on), 0);
+    }
+
+    #[test]
+    fn test_cycle() {
+        let mut blinker = Automaton::new(life(".....\n..#..\n..#..\n..#..\n.....\n"), Neighborhood::Moore, Boundary::Fixed);
+        assert_eq!(blinker.find_cycle(conway), Cycle { start: 0, len: 2 });
+        blinker.run(3, conway);
+        assert_eq!(blinker.find_cycle(conway), Cycle { start: 5, len: 2 });
+        assert_eq!(blinker.generation(), 7);
+
+        // A glider comes back to where it started after crossing the torus
+        let glider = ".#....\n..#...\n###...\n......\n......\n......\n";
+        let mut torus = Automaton::new(life(glider), Neighborhood::Moore, Boundary::Toroidal);
+        assert_eq!(torus.find_cycle(conway), Cycle { start: 0, len: 24 });
+        assert_eq!(torus.cells(), &life(glider));
+
+        let mut fixed = Automaton::new(life(glider), Neighborhood::Moore, Boundary::Fixed);
+        assert_eq!(fixed.find_cycle(conway).len, 1);
+        assert_eq!(fixed.count(|&on| on), 4);
+    }
+
+    #[test]
+    fn test_pinned() {
+        let mut grid = Automaton::new(life("...\n...\n...\n"), Neighborhood::Moore, Boundary::Fixed);
+        grid.pin((0, 0), true);
+        grid.pin((2, 2), true);
+        assert!(!grid.step(conway));
+        assert_eq!(grid.count(|&on| on), 2);
+        grid.pin((2, 0), true);
+        grid.run(1, conway);
+        assert_eq!(grid.count(|&on| on), 4);
+        assert_eq!(grid.generation(), 2);
+    }
+
+    #[test]
+    fn test_neighborhoods() {
+        let count = |neighborhood, boundary| {
+            let mut grid = Automaton::new(life("#.#\n...\n#.#\n"), neighborhood, boundary);
+            grid.step(|_, neighbors| neighbors.iter().filter(|&&on| on).count() > 0);
+            grid.count(|&on| on)
+        };
+        assert_eq!(count(Neighborhood::Moore, Boundary::Fixed), 5);
+        assert_eq!(count(Neighborhood::VonNeumann, Boundary::Fixed), 4);
+        assert_eq!(count(Neighborhood::VonNeumann, Boundary::Toroidal), 8);
+
+        // Seeing past the empty cells, the corners see each other
+        assert_eq!(count(Neighborhood::LineOfSight(|&on| !on), Boundary::Fixed), 9);
+    }
+
+    #[test]
+    fn test_sparse() {
+        // The example of 2020 day 17, in three dimensions
+        let cubes = [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)].map(|(x, y)| Point3::new(x, y, 0));
+        let mut space = Sparse::new(cubes, Point3::neighbors);
+        assert!(space.step(|alive, n| n == 3 || (alive && n == 2)));
+        assert_eq!(space.live().len(), 11);
+        space.run(5, |alive, n| n == 3 || (alive && n == 2));
+        assert_eq!(space.live().len(), 112);
+        assert_eq!(space.generation(), 6);
+    }
+
+    #[test]
+    fn test_population() {
+        // The lanternfish of 2021 day 6
+        let mut transitions: Vec<Vec<usize>> = (0..9).map(|timer| vec![timer.max(1) - 1]).collect();
+        transitions[0] = vec![6, 8];
+        let mut fish = Population::new(transitions);
+        for timer in [3, 4, 3, 1, 2] {
+            fish.add(timer, 1);
+        }
+        fish.run(18);
+        assert_eq!(fish.total(), 26);
+        assert_eq!(fish.count(8), 4);
+    }
+}
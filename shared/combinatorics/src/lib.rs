@@ -0,0 +1,219 @@
+//! Iterators over the ways to order, choose and divide things, for the
+//! puzzles that are solved by trying every possibility.
+//!
+//! The iterators are lazy and yield a new `Vec` for every item.
+
+/// Every ordering of the items, from [`permutations`].
+#[derive(Clone, Debug)]
+pub struct Permutations<T> {
+    items: Vec<T>,
+    /// The loop counters of Heap's algorithm.
+    counters: Vec<usize>,
+    i: usize,
+    started: bool,
+}
+
+/// Every ordering of the items, in the order of Heap's algorithm: each
+/// permutation differs from the one before by swapping two items. There
+/// are `n!` of them, starting with the items in their given order.
+pub fn permutations<T: Clone>(items: &[T]) -> Permutations<T> {
+    Permutations { items: items.to_vec(), counters: vec![0; items.len()], i: 1, started: false }
+}
+
+impl<T: Clone> Iterator for Permutations<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        if !self.started {
+            self.started = true;
+            return Some(self.items.clone());
+        }
+
+        while self.i < self.items.len() {
+            let i = self.i;
+            if self.counters[i] < i {
+                let j = if i.is_multiple_of(2) { 0 } else { self.counters[i] };
+                self.items.swap(j, i);
+                self.counters[i] += 1;
+                self.i = 1;
+                return Some(self.items.clone());
+            }
+            self.counters[i] = 0;
+            self.i += 1;
+        }
+        None
+    }
+}
+
+/// Every choice of `k` items, from [`combinations`].
+#[derive(Clone, Debug)]
+pub struct Combinations<T> {
+    items: Vec<T>,
+    indices: Option<Vec<usize>>,
+}
+
+/// Every choice of `k` of the items, keeping them in their given order.
+/// The choices come in lexicographic order of the positions of the items.
+pub fn combinations<T: Clone>(items: &[T], k: usize) -> Combinations<T> {
+    let indices = (k <= items.len()).then(|| (0..k).collect());
+    Combinations { items: items.to_vec(), indices }
+}
+
+impl<T: Clone> Iterator for Combinations<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        let indices = self.indices.as_mut()?;
+        let combination = indices.iter().map(|&i| self.items[i].clone()).collect();
+
+        // Move the last index that can move, and put the ones after it
+        // right behind it.
+        let (n, k) = (self.items.len(), indices.len());
+        match (0..k).rev().find(|&i| indices[i] < n - k + i) {
+            Some(i) => {
+                indices[i] += 1;
+                for j in i + 1..k {
+                    indices[j] = indices[j - 1] + 1;
+                }
+            }
+            None => self.indices = None,
+        }
+        Some(combination)
+    }
+}
+
+/// Every subset of the items, from [`subsets`].
+#[derive(Clone, Debug)]
+pub struct Subsets<T> {
+    items: Vec<T>,
+    mask: u64,
+    done: bool,
+}
+
+/// Every subset of the items, keeping them in their given order. Subset
+/// number `m` has the items whose bits are set in `m`, so the first is
+/// empty and the last has all of them.
+///
+/// # Panics
+///
+/// If there are 64 items or more.
+pub fn subsets<T: Clone>(items: &[T]) -> Subsets<T> {
+    assert!(items.len() < 64, "too many items for subsets: {}", items.len());
+    Subsets { items: items.to_vec(), mask: 0, done: false }
+}
+
+impl<T: Clone> Iterator for Subsets<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        if self.done {
+            return None;
+        }
+
+        let subset = self
+            .items
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| self.mask & 1 << i != 0)
+            .map(|(_, item)| item.clone())
+            .collect();
+
+        self.mask += 1;
+        self.done = self.mask == 1 << self.items.len();
+        Some(subset)
+    }
+}
+
+/// Every way to divide a number into parts, from [`compositions`].
+#[derive(Clone, Debug)]
+pub struct Compositions {
+    parts: Option<Vec<u32>>,
+}
+
+/// Every way to divide `total` into `k` parts of zero or more, where the
+/// order of the parts matters. The ways come in lexicographic order, from
+/// everything in the last part to everything in the first.
+pub fn compositions(total: u32, k: usize) -> Compositions {
+    let parts = match k {
+        0 => (total == 0).then(Vec::new),
+        _ => {
+            let mut parts = vec![0; k];
+            parts[k - 1] = total;
+            Some(parts)
+        }
+    };
+    Compositions { parts }
+}
+
+impl Iterator for Compositions {
+    type Item = Vec<u32>;
+
+    fn next(&mut self) -> Option<Vec<u32>> {
+        let parts = self.parts.as_mut()?;
+        let composition = parts.clone();
+
+        // Move one from the last part that is not empty to the part before
+        // it, and everything after that part to the end.
+        match parts.iter().rposition(|&n| n > 0) {
+            Some(last) if last > 0 => {
+                let rest = parts[last] - 1;
+                parts[last] = 0;
+                parts[last - 1] += 1;
+                let end = parts.len() - 1;
+                parts[end] = rest;
+            }
+            _ => self.parts = None,
+        }
+        Some(composition)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_permutations() {
+        let mut all: Vec<Vec<char>> = permutations(&['a', 'b', 'c']).collect();
+        assert_eq!(all[0], ['a', 'b', 'c']);
+        assert!(all.windows(2).all(|w| w[0].iter().zip(&w[1]).filter(|(a, b)| a != b).count() == 2));
+        all.sort();
+        all.dedup();
+        assert_eq!(all.len(), 6);
+
+        assert_eq!(permutations(&[0; 6]).count(), 720);
+        assert_eq!(permutations(&[1]).collect::<Vec<_>>(), [[1]]);
+        assert_eq!(permutations::<u8>(&[]).collect::<Vec<_>>(), [[]]);
+    }
+
+    #[test]
+    fn test_combinations() {
+        let pairs: Vec<Vec<u8>> = combinations(&[1, 2, 3, 4], 2).collect();
+        assert_eq!(pairs, [[1, 2], [1, 3], [1, 4], [2, 3], [2, 4], [3, 4]]);
+        assert_eq!(combinations(&[0; 10], 3).count(), 120);
+        assert_eq!(combinations(&[1, 2], 0).collect::<Vec<_>>(), [[]]);
+        assert_eq!(combinations(&[1, 2], 2).collect::<Vec<_>>(), [[1, 2]]);
+        assert_eq!(combinations(&[1, 2], 3).count(), 0);
+    }
+
+    #[test]
+    fn test_subsets() {
+        let all: Vec<Vec<char>> = subsets(&['a', 'b', 'c']).collect();
+        assert_eq!(all.len(), 8);
+        assert_eq!(all[0], []);
+        assert_eq!(all[5], ['a', 'c']);
+        assert_eq!(all[7], ['a', 'b', 'c']);
+        assert_eq!(subsets::<u8>(&[]).collect::<Vec<_>>(), [[]]);
+    }
+
+    #[test]
+    fn test_compositions() {
+        let all: Vec<Vec<u32>> = compositions(2, 3).collect();
+        assert_eq!(all, [[0, 0, 2], [0, 1, 1], [0, 2, 0], [1, 0, 1], [1, 1, 0], [2, 0, 0]]);
+        assert!(compositions(100, 4).all(|parts| parts.iter().sum::<u32>() == 100));
+        assert_eq!(compositions(100, 4).count(), 176851);
+        assert_eq!(compositions(5, 1).collect::<Vec<_>>(), [[5]]);
+        assert_eq!(compositions(0, 0).collect::<Vec<_>>(), [[]]);
+        assert_eq!(compositions(1, 0).count(), 0);
+    }
+}
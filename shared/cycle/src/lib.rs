@@ -0,0 +1,184 @@
+//! Finding where a deterministic simulation starts repeating itself, to
+//! skip ahead to a far-away step.
+//!
+//! A simulation is given as a first state and a function from a state to
+//! the next one. Every function here runs until a state comes back, so it
+//! never returns for a simulation that does not repeat.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Where the states start repeating: the one at step `start` comes back
+/// after every `len` steps.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Cycle {
+    pub start: usize,
+    pub len: usize,
+}
+
+impl Cycle {
+    /// The first step with the same state as step `n`.
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.len
+        }
+    }
+}
+
+/// Floyd's tortoise and hare, which keeps two states at a time.
+pub fn floyd<S: Clone + PartialEq>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    // The hare goes twice as fast, so they meet somewhere in the cycle
+    let mut tortoise = step(&initial);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        let next = step(&hare);
+        hare = step(&next);
+    }
+
+    let mut start = 0;
+    tortoise = initial;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    let mut len = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        len += 1;
+    }
+
+    Cycle { start, len }
+}
+
+/// Brent's algorithm, which keeps two states at a time like [`floyd`] but
+/// takes fewer steps.
+pub fn brent<S: Clone + PartialEq>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    // The tortoise jumps to the hare at every power of two, until the hare
+    // runs into it
+    let (mut power, mut len) = (1, 1);
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == len {
+            tortoise = hare.clone();
+            power *= 2;
+            len = 0;
+        }
+        hare = step(&hare);
+        len += 1;
+    }
+
+    let mut start = 0;
+    tortoise = initial.clone();
+    hare = initial;
+    for _ in 0..len {
+        hare = step(&hare);
+    }
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    Cycle { start, len }
+}
+
+/// Step until a state comes back, remembering the step at which every state
+/// was first seen. Returns the cycle and the state that came back.
+pub fn find<S: Clone + Eq + Hash>(initial: S, step: impl FnMut(&S) -> S) -> (Cycle, S) {
+    find_by_key(initial, step, S::clone)
+}
+
+/// Like [`find`], but two states count as the same when their keys are, for
+/// when only part of a state has to repeat.
+pub fn find_by_key<S, K: Eq + Hash>(
+    initial: S,
+    mut step: impl FnMut(&S) -> S,
+    mut key: impl FnMut(&S) -> K,
+) -> (Cycle, S) {
+    let mut seen = HashMap::new();
+    let mut state = initial;
+    for n in 0.. {
+        if let Some(start) = seen.insert(key(&state), n) {
+            return (Cycle { start, len: n - start }, state);
+        }
+        state = step(&state);
+    }
+    unreachable!()
+}
+
+/// The state after `n` steps, skipping ahead as soon as a state comes back.
+/// The states up to the first repeat are kept.
+pub fn nth<S: Clone + Eq + Hash>(initial: S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+    let mut states = Vec::new();
+    let mut seen = HashMap::new();
+    let mut state = initial;
+    while states.len() < n {
+        if let Some(&start) = seen.get(&state) {
+            let cycle = Cycle { start, len: states.len() - start };
+            return states.swap_remove(cycle.reduce(n));
+        }
+        seen.insert(state.clone(), states.len());
+        let next = step(&state);
+        states.push(state);
+        state = next;
+    }
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Squaring modulo 1000 from 3: 3, 9, 81, 561, 721, 841, 281, 961, 521,
+    /// 441, 481, 361, 321, 41, 681, 761, 121, 641, 881, 161, 921, 241, 81,
+    /// so step 22 is step 2 again.
+    fn square(n: &u64) -> u64 {
+        n * n % 1000
+    }
+
+    const SQUARES: Cycle = Cycle { start: 2, len: 20 };
+
+    #[test]
+    fn test_algorithms() {
+        assert_eq!(floyd(3, square), SQUARES);
+        assert_eq!(brent(3, square), SQUARES);
+        assert_eq!(find(3, square), (SQUARES, 81));
+
+        // A cycle from the start, and a fixed point
+        assert_eq!(brent(0, |n| (n + 1) % 7), Cycle { start: 0, len: 7 });
+        assert_eq!(floyd(0, |n| (n + 1) % 7), Cycle { start: 0, len: 7 });
+        assert_eq!(floyd(5, |n: &u32| n / 2), Cycle { start: 3, len: 1 });
+        assert_eq!(brent(5, |n: &u32| n / 2), Cycle { start: 3, len: 1 });
+    }
+
+    #[test]
+    fn test_find_by_key() {
+        // Only the last digit has to repeat: 3, 9, 1, 1
+        let (cycle, state) = find_by_key(3, square, |n| n % 10);
+        assert_eq!(cycle, Cycle { start: 2, len: 1 });
+        assert_eq!(state, 561);
+    }
+
+    #[test]
+    fn test_nth() {
+        assert_eq!(SQUARES.reduce(1), 1);
+        assert_eq!(SQUARES.reduce(22), 2);
+        assert_eq!(SQUARES.reduce(45), 5);
+
+        let mut n = 3;
+        for _ in 0..45 {
+            n = square(&n);
+        }
+        assert_eq!(nth(3, square, 45), n);
+        assert_eq!(nth(3, square, 0), 3);
+        assert_eq!(nth(3, square, 4), 721);
+        assert_eq!(nth(3, square, 1_000_000_000_000), nth(3, square, SQUARES.reduce(1_000_000_000_000)));
+    }
+}
@@ -0,0 +1,267 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// The base of the digits, the largest power of ten whose square fits in a
+/// `u64`, which makes printing in decimal easy.
+const BASE: u64 = 1_000_000_000;
+
+/// A signed integer without a limit on its size.
+#[derive(Clone, Default, PartialEq, Eq, Hash, Debug)]
+pub struct BigInt {
+    negative: bool,
+    /// The magnitude in base `BASE`, least significant digit first, without
+    /// zeros at the end. Zero has no digits and is never negative.
+    digits: Vec<u64>,
+}
+
+impl BigInt {
+    fn new(negative: bool, mut digits: Vec<u64>) -> Self {
+        trim(&mut digits);
+        let negative = negative && !digits.is_empty();
+        Self { negative, digits }
+    }
+
+    /// The number with the given decimal digits, which are all ASCII
+    /// digits.
+    pub(crate) fn from_decimal(s: &str) -> Self {
+        let digits = s
+            .as_bytes()
+            .rchunks(9)
+            .map(|chunk| chunk.iter().fold(0, |n, &d| n * 10 + u64::from(d - b'0')))
+            .collect();
+        BigInt::new(false, digits)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.digits.is_empty()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// The quotient rounded towards zero and the remainder, which has the
+    /// sign of `self`, or `None` when dividing by zero.
+    pub fn div_rem(&self, other: &BigInt) -> Option<(BigInt, BigInt)> {
+        if other.is_zero() {
+            return None;
+        }
+
+        // Long division, finding each digit of the quotient with a binary
+        // search
+        let mut quotient = vec![0; self.digits.len()];
+        let mut remainder = Vec::new();
+        for i in (0..self.digits.len()).rev() {
+            remainder.insert(0, self.digits[i]);
+            trim(&mut remainder);
+            let (mut low, mut high) = (0, BASE - 1);
+            while low < high {
+                let mid = (low + high).div_ceil(2);
+                if cmp(&mul_digit(&other.digits, mid), &remainder) == Ordering::Greater {
+                    high = mid - 1;
+                } else {
+                    low = mid;
+                }
+            }
+            quotient[i] = low;
+            remainder = sub(&remainder, &mul_digit(&other.digits, low));
+        }
+
+        Some((
+            BigInt::new(self.negative != other.negative, quotient),
+            BigInt::new(self.negative, remainder),
+        ))
+    }
+}
+
+fn trim(digits: &mut Vec<u64>) {
+    while digits.last() == Some(&0) {
+        digits.pop();
+    }
+}
+
+fn cmp(a: &[u64], b: &[u64]) -> Ordering {
+    a.len().cmp(&b.len()).then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut sum = Vec::with_capacity(a.len().max(b.len()) + 1);
+    let mut carry = 0;
+    for i in 0..a.len().max(b.len()) {
+        let digit = a.get(i).unwrap_or(&0) + b.get(i).unwrap_or(&0) + carry;
+        sum.push(digit % BASE);
+        carry = digit / BASE;
+    }
+    sum.push(carry);
+    trim(&mut sum);
+    sum
+}
+
+/// `a - b`, where `a` is at least `b`.
+fn sub(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut difference = Vec::with_capacity(a.len());
+    let mut borrow = 0;
+    for (i, &digit) in a.iter().enumerate() {
+        let subtract = b.get(i).unwrap_or(&0) + borrow;
+        if digit >= subtract {
+            difference.push(digit - subtract);
+            borrow = 0;
+        } else {
+            difference.push(digit + BASE - subtract);
+            borrow = 1;
+        }
+    }
+    trim(&mut difference);
+    difference
+}
+
+fn mul(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut product = vec![0; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0;
+        for (j, &y) in b.iter().enumerate() {
+            let digit = product[i + j] + x * y + carry;
+            product[i + j] = digit % BASE;
+            carry = digit / BASE;
+        }
+        product[i + b.len()] += carry;
+    }
+    trim(&mut product);
+    product
+}
+
+fn mul_digit(a: &[u64], digit: u64) -> Vec<u64> {
+    mul(a, &[digit])
+}
+
+impl From<u64> for BigInt {
+    fn from(mut n: u64) -> Self {
+        let mut digits = Vec::new();
+        while n > 0 {
+            digits.push(n % BASE);
+            n /= BASE;
+        }
+        BigInt::new(false, digits)
+    }
+}
+
+impl From<i64> for BigInt {
+    fn from(n: i64) -> Self {
+        let magnitude = BigInt::from(n.unsigned_abs());
+        BigInt::new(n < 0, magnitude.digits)
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt::new(!self.negative, self.digits)
+    }
+}
+
+impl Add for BigInt {
+    type Output = BigInt;
+
+    fn add(self, other: BigInt) -> BigInt {
+        if self.negative == other.negative {
+            return BigInt::new(self.negative, add(&self.digits, &other.digits));
+        }
+        // The sign of the one with the larger magnitude wins
+        match cmp(&self.digits, &other.digits) {
+            Ordering::Less => BigInt::new(other.negative, sub(&other.digits, &self.digits)),
+            _ => BigInt::new(self.negative, sub(&self.digits, &other.digits)),
+        }
+    }
+}
+
+impl Sub for BigInt {
+    type Output = BigInt;
+
+    fn sub(self, other: BigInt) -> BigInt {
+        self + -other
+    }
+}
+
+impl Mul for BigInt {
+    type Output = BigInt;
+
+    fn mul(self, other: BigInt) -> BigInt {
+        BigInt::new(self.negative != other.negative, mul(&self.digits, &other.digits))
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => cmp(&self.digits, &other.digits),
+            (true, true) => cmp(&other.digits, &self.digits),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let Some((last, rest)) = self.digits.split_last() else {
+            return f.write_str("0");
+        };
+        if self.negative {
+            f.write_str("-")?;
+        }
+        write!(f, "{}", last)?;
+        for digit in rest.iter().rev() {
+            write!(f, "{:09}", digit)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn big(n: i64) -> BigInt {
+        BigInt::from(n)
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        for a in [0, 1, -1, 7, -7, 999_999_999, 1_000_000_000, -123_456_789_012, i64::MAX / 3] {
+            for b in [1, -1, 3, -3, 999_999_999, 1_000_000_001, -98_765_432_109] {
+                let (x, y) = (big(a), big(b));
+                assert_eq!(x.clone() + y.clone(), big(a + b), "{} + {}", a, b);
+                assert_eq!(x.clone() - y.clone(), big(a - b), "{} - {}", a, b);
+                assert_eq!(x.div_rem(&y), Some((big(a / b), big(a % b))), "{} / {}", a, b);
+                assert_eq!(x.cmp(&y), a.cmp(&b));
+                if let Some(product) = a.checked_mul(b) {
+                    assert_eq!(x * y, big(product), "{} * {}", a, b);
+                }
+            }
+        }
+        assert_eq!(big(5).div_rem(&big(0)), None);
+    }
+
+    #[test]
+    fn test_large() {
+        let n = BigInt::from(u64::MAX);
+        let square = n.clone() * n.clone();
+        assert_eq!(square.to_string(), "340282366920938463426481119284349108225");
+        assert_eq!(BigInt::from_decimal("000340282366920938463426481119284349108225"), square);
+        assert_eq!(BigInt::from_decimal("000"), BigInt::default());
+        assert_eq!(square.div_rem(&n), Some((n.clone(), BigInt::default())));
+        let (quotient, remainder) = (square.clone() + big(12345)).div_rem(&big(-1_000_000_007)).unwrap();
+        assert_eq!(quotient * big(-1_000_000_007) + remainder, square + big(12345));
+        assert_eq!(big(-1_000_000_000).to_string(), "-1000000000");
+        assert_eq!((big(3) - big(3)).to_string(), "0");
+        assert!(!(big(3) - big(3)).is_negative());
+    }
+}
@@ -0,0 +1,445 @@
+//! Parsing and evaluating arithmetic expressions with made-up precedence
+//! rules, like `1 + 2 * 3` where addition comes first.
+//!
+//! The operators are binary and have one character each. A [`Table`] gives
+//! them a precedence and associativity, and parses an expression into an
+//! [`Expr`] with precedence climbing. Operands are unsigned literals of any
+//! length, variables and expressions between parentheses.
+
+use std::error;
+use std::fmt;
+
+mod bigint;
+
+pub use bigint::BigInt;
+
+/// Which side of an expression like `a - b - c` is grouped first.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Assoc {
+    /// `(a - b) - c`
+    Left,
+    /// `a - (b - c)`
+    Right,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+struct Operator {
+    symbol: char,
+    precedence: u8,
+    assoc: Assoc,
+}
+
+/// The binary operators of an expression language. Operators with a higher
+/// precedence bind more tightly.
+#[derive(Clone, Default, Debug)]
+pub struct Table {
+    operators: Vec<Operator>,
+}
+
+impl Table {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an operator, replacing the one with the same symbol if any.
+    pub fn op(mut self, symbol: char, precedence: u8, assoc: Assoc) -> Self {
+        self.operators.retain(|op| op.symbol != symbol);
+        self.operators.push(Operator { symbol, precedence, assoc });
+        self
+    }
+
+    fn get(&self, symbol: char) -> Option<Operator> {
+        self.operators.iter().copied().find(|op| op.symbol == symbol)
+    }
+
+    /// Parse an expression on one line.
+    pub fn parse(&self, s: &str) -> Result<Expr, ParseError> {
+        let tokens = self.tokenize(s)?;
+        let mut parser = Parser { table: self, tokens, next: 0 };
+        let expr = parser.expr(0)?;
+        match parser.tokens.get(parser.next) {
+            Some(token) => Err(token.unexpected()),
+            None => Ok(expr),
+        }
+    }
+
+    fn tokenize<'a>(&self, s: &'a str) -> Result<Vec<Token<'a>>, ParseError> {
+        let mut tokens = Vec::new();
+        let mut chars = s.char_indices().enumerate().peekable();
+        while let Some((column, (start, c))) = chars.next() {
+            let column = column + 1;
+            let kind = match c {
+                c if c.is_whitespace() => continue,
+                '(' => Kind::Open,
+                ')' => Kind::Close,
+                c if c.is_ascii_digit() || c.is_alphabetic() || c == '_' => {
+                    // A literal is all digits, a variable starts with a letter
+                    let number = c.is_ascii_digit();
+                    let mut end = start + c.len_utf8();
+                    while let Some(&(_, (i, c))) = chars.peek() {
+                        let more = if number { c.is_ascii_digit() } else { c.is_alphanumeric() || c == '_' };
+                        if !more {
+                            break;
+                        }
+                        end = i + c.len_utf8();
+                        chars.next();
+                    }
+                    let text = &s[start..end];
+                    if number {
+                        Kind::Num(text)
+                    } else {
+                        Kind::Var(text)
+                    }
+                }
+                c if self.get(c).is_some() => Kind::Op(c),
+                c => return Err(ParseError::Char { column, c }),
+            };
+            tokens.push(Token { kind, column });
+        }
+        Ok(tokens)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Kind<'a> {
+    Num(&'a str),
+    Var(&'a str),
+    Op(char),
+    Open,
+    Close,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Token<'a> {
+    kind: Kind<'a>,
+    column: usize,
+}
+
+impl Token<'_> {
+    fn unexpected(&self) -> ParseError {
+        let found = match self.kind {
+            Kind::Num(text) | Kind::Var(text) => text.to_owned(),
+            Kind::Op(symbol) => symbol.to_string(),
+            Kind::Open => "(".to_owned(),
+            Kind::Close => ")".to_owned(),
+        };
+        ParseError::Unexpected { column: self.column, found }
+    }
+}
+
+struct Parser<'t, 'a> {
+    table: &'t Table,
+    tokens: Vec<Token<'a>>,
+    next: usize,
+}
+
+impl Parser<'_, '_> {
+    /// An expression whose operators all have at least the given
+    /// precedence.
+    fn expr(&mut self, min: u8) -> Result<Expr, ParseError> {
+        let mut lhs = self.operand()?;
+        while let Some(&Token { kind: Kind::Op(symbol), .. }) = self.tokens.get(self.next) {
+            let op = self.table.get(symbol).expect("tokens are operators of the table");
+            if op.precedence < min {
+                break;
+            }
+            self.next += 1;
+
+            // The right-hand side takes the operators that bind tighter, and
+            // for right associativity the ones that bind the same as well.
+            // Nothing binds tighter than the highest precedence.
+            let rhs = match op.assoc {
+                Assoc::Left => match op.precedence.checked_add(1) {
+                    Some(min) => self.expr(min)?,
+                    None => self.operand()?,
+                },
+                Assoc::Right => self.expr(op.precedence)?,
+            };
+            lhs = Expr::Binary(symbol, Box::new(lhs), Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    fn operand(&mut self) -> Result<Expr, ParseError> {
+        let token = *self.tokens.get(self.next).ok_or(ParseError::End)?;
+        self.next += 1;
+        match token.kind {
+            Kind::Num(digits) => Ok(Expr::Num(digits.to_owned())),
+            Kind::Var(name) => Ok(Expr::Var(name.to_owned())),
+            Kind::Open => {
+                let expr = self.expr(0)?;
+                match self.tokens.get(self.next) {
+                    Some(Token { kind: Kind::Close, .. }) => {
+                        self.next += 1;
+                        Ok(expr)
+                    }
+                    Some(other) => Err(other.unexpected()),
+                    None => Err(ParseError::Unclosed { column: token.column }),
+                }
+            }
+            Kind::Op(_) | Kind::Close => Err(token.unexpected()),
+        }
+    }
+}
+
+/// An expression that could not be parsed. Columns count characters from 1.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ParseError {
+    /// The expression stops where an operand should come.
+    End,
+    /// A character that is no operand, parenthesis or operator of the table.
+    Char { column: usize, c: char },
+    /// A token in a place where it does not belong.
+    Unexpected { column: usize, found: String },
+    /// An opening parenthesis without a closing one.
+    Unclosed { column: usize },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::End => f.write_str("Unexpected end of expression"),
+            ParseError::Char { column, c } => write!(f, "Unknown character {:?} at column {}", c, column),
+            ParseError::Unexpected { column, found } => {
+                write!(f, "Unexpected {:?} at column {}", found, column)
+            }
+            ParseError::Unclosed { column } => write!(f, "Parenthesis at column {} is never closed", column),
+        }
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// A parsed expression.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Expr {
+    /// A literal with its decimal digits, which can be too many for the
+    /// integer types.
+    Num(String),
+    Var(String),
+    /// An operator with its left and right operands.
+    Binary(char, Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    /// Evaluate the expression, looking up the value of every variable with
+    /// `vars`.
+    pub fn eval<V: Value>(&self, vars: impl Fn(&str) -> Option<V>) -> Result<V, EvalError> {
+        self.eval_with(&vars)
+    }
+
+    fn eval_with<V: Value>(&self, vars: &dyn Fn(&str) -> Option<V>) -> Result<V, EvalError> {
+        match self {
+            Expr::Num(digits) => V::literal(digits),
+            Expr::Var(name) => vars(name).ok_or_else(|| EvalError::Variable(name.clone())),
+            Expr::Binary(op, lhs, rhs) => V::apply(*op, lhs.eval_with(vars)?, rhs.eval_with(vars)?),
+        }
+    }
+}
+
+/// Every operation between parentheses, as in `(1 + (2 * 3))`.
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Expr::Num(digits) => f.write_str(digits),
+            Expr::Var(name) => f.write_str(name),
+            Expr::Binary(op, lhs, rhs) => write!(f, "({} {} {})", lhs, op, rhs),
+        }
+    }
+}
+
+/// An expression that could not be evaluated.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum EvalError {
+    /// A variable without a value.
+    Variable(String),
+    /// An operator that means nothing for the type of the values.
+    Operator(char),
+    /// A result that does not fit in the type of the values.
+    Overflow,
+    DivisionByZero,
+}
+
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EvalError::Variable(name) => write!(f, "Variable {:?} has no value", name),
+            EvalError::Operator(op) => write!(f, "Unknown operator {:?}", op),
+            EvalError::Overflow => f.write_str("Overflow"),
+            EvalError::DivisionByZero => f.write_str("Division by zero"),
+        }
+    }
+}
+
+impl error::Error for EvalError {}
+
+/// The values that an expression evaluates to.
+///
+/// The integers and [`BigInt`] know the operators `+`, `-`, `*`, `/` and
+/// `%`, where division rounds towards zero.
+pub trait Value: Sized {
+    /// The value of a literal from its decimal digits.
+    fn literal(digits: &str) -> Result<Self, EvalError>;
+
+    fn apply(op: char, lhs: Self, rhs: Self) -> Result<Self, EvalError>;
+}
+
+macro_rules! value {
+    ($($t:ty),*) => {
+        $(impl Value for $t {
+            fn literal(digits: &str) -> Result<Self, EvalError> {
+                digits.parse().map_err(|_| EvalError::Overflow)
+            }
+
+            fn apply(op: char, lhs: Self, rhs: Self) -> Result<Self, EvalError> {
+                if matches!(op, '/' | '%') && rhs == 0 {
+                    return Err(EvalError::DivisionByZero);
+                }
+                let result = match op {
+                    '+' => lhs.checked_add(rhs),
+                    '-' => lhs.checked_sub(rhs),
+                    '*' => lhs.checked_mul(rhs),
+                    '/' => lhs.checked_div(rhs),
+                    '%' => lhs.checked_rem(rhs),
+                    _ => return Err(EvalError::Operator(op)),
+                };
+                result.ok_or(EvalError::Overflow)
+            }
+        })*
+    };
+}
+
+value!(i32, i64, i128, u32, u64, u128);
+
+impl Value for BigInt {
+    fn literal(digits: &str) -> Result<Self, EvalError> {
+        Ok(BigInt::from_decimal(digits))
+    }
+
+    fn apply(op: char, lhs: Self, rhs: Self) -> Result<Self, EvalError> {
+        match op {
+            '+' => Ok(lhs + rhs),
+            '-' => Ok(lhs - rhs),
+            '*' => Ok(lhs * rhs),
+            '/' | '%' => {
+                let (quotient, remainder) = lhs.div_rem(&rhs).ok_or(EvalError::DivisionByZero)?;
+                Ok(if op == '/' { quotient } else { remainder })
+            }
+            _ => Err(EvalError::Operator(op)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn arithmetic() -> Table {
+        Table::new()
+            .op('+', 1, Assoc::Left)
+            .op('-', 1, Assoc::Left)
+            .op('*', 2, Assoc::Left)
+            .op('/', 2, Assoc::Left)
+            .op('%', 2, Assoc::Left)
+    }
+
+    fn parsed(table: &Table, s: &str) -> String {
+        table.parse(s).unwrap().to_string()
+    }
+
+    #[test]
+    fn test_precedence() {
+        let table = arithmetic();
+        assert_eq!(parsed(&table, "1 + 2 * 3"), "(1 + (2 * 3))");
+        assert_eq!(parsed(&table, "1 * 2 + 3"), "((1 * 2) + 3)");
+        assert_eq!(parsed(&table, "(1 + 2) * 3"), "((1 + 2) * 3)");
+        assert_eq!(parsed(&table, "8 - 4 - 2"), "((8 - 4) - 2)");
+        assert_eq!(parsed(&table, "((42))"), "42");
+
+        let table = table.op('-', 1, Assoc::Right).op('*', 0, Assoc::Left);
+        assert_eq!(parsed(&table, "8 - 4 - 2"), "(8 - (4 - 2))");
+        assert_eq!(parsed(&table, "1 + 2 * 3"), "((1 + 2) * 3)");
+
+        // The highest precedence, which nothing binds tighter than
+        let table = table.op('*', u8::MAX, Assoc::Left).op('/', u8::MAX, Assoc::Right);
+        assert_eq!(parsed(&table, "1 * 2 * 3 + 4"), "(((1 * 2) * 3) + 4)");
+        assert_eq!(parsed(&table, "8 / 4 / 2"), "(8 / (4 / 2))");
+    }
+
+    #[test]
+    fn test_operands() {
+        let table = arithmetic();
+        assert_eq!(parsed(&table, "123*x_1+ foo"), "((123 * x_1) + foo)");
+        assert_eq!(
+            table.parse("2x"),
+            Err(ParseError::Unexpected { column: 2, found: "x".to_owned() })
+        );
+
+        let expr = table.parse("width * height - 10 % 4").unwrap();
+        let vars = |name: &str| match name {
+            "width" => Some(6),
+            "height" => Some(7),
+            _ => None,
+        };
+        assert_eq!(expr.eval::<i64>(vars), Ok(40));
+        assert_eq!(expr.eval::<i64>(|_| None), Err(EvalError::Variable("width".to_owned())));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let table = arithmetic();
+        assert_eq!(table.parse(""), Err(ParseError::End));
+        assert_eq!(table.parse("1 +"), Err(ParseError::End));
+        assert_eq!(table.parse("1 ^ 2"), Err(ParseError::Char { column: 3, c: '^' }));
+        assert_eq!(table.parse("1 + (2 * 3"), Err(ParseError::Unclosed { column: 5 }));
+        assert_eq!(
+            table.parse("(1 + 2))"),
+            Err(ParseError::Unexpected { column: 8, found: ")".to_owned() })
+        );
+        assert_eq!(
+            table.parse("1 * * 2"),
+            Err(ParseError::Unexpected { column: 5, found: "*".to_owned() })
+        );
+        assert_eq!(
+            table.parse("(1 2)"),
+            Err(ParseError::Unexpected { column: 4, found: "2".to_owned() })
+        );
+    }
+
+    #[test]
+    fn test_eval() {
+        let table = arithmetic();
+        let eval = |s: &str| table.parse(s).unwrap().eval::<i64>(|_| None);
+        assert_eq!(eval("2 + 3 * 4 - 10 / 3"), Ok(11));
+        assert_eq!(eval("0 - 7 / 2"), Ok(-3));
+        assert_eq!(eval("0 - 7 % 2"), Ok(-1));
+        assert_eq!(eval("1 / (2 - 2)"), Err(EvalError::DivisionByZero));
+        assert_eq!(eval("9999999999 * 9999999999"), Err(EvalError::Overflow));
+        assert_eq!(eval("99999999999999999999"), Err(EvalError::Overflow));
+        assert_eq!(table.parse("4294967296").unwrap().eval::<u32>(|_| None), Err(EvalError::Overflow));
+        assert_eq!(table.parse("2 - 3").unwrap().eval::<u64>(|_| None), Err(EvalError::Overflow));
+
+        let table = table.op('^', 3, Assoc::Right);
+        assert_eq!(table.parse("2 ^ 3").unwrap().eval::<i64>(|_| None), Err(EvalError::Operator('^')));
+    }
+
+    #[test]
+    fn test_eval_big() {
+        let table = arithmetic();
+        let eval = |s: &str| table.parse(s).unwrap().eval::<BigInt>(|_| None).map(|n| n.to_string());
+        assert_eq!(eval("9999999999 * 9999999999").unwrap(), "99999999980000000001");
+        assert_eq!(
+            eval("18446744073709551615 * 18446744073709551615 * 10 + 7").unwrap(),
+            "3402823669209384634264811192843491082257"
+        );
+        assert_eq!(eval("3 - 18446744073709551615 * 18446744073709551615").unwrap(), "-340282366920938463426481119284349108222");
+        assert_eq!(eval("1 / 0"), Err(EvalError::DivisionByZero));
+
+        // Literals that do not fit in a u64
+        assert_eq!(eval("99999999999999999999 + 1").unwrap(), "100000000000000000000");
+        assert_eq!(
+            eval("340282366920938463426481119284349108225 / 18446744073709551615").unwrap(),
+            "18446744073709551615"
+        );
+    }
+}
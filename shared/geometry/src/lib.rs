@@ -0,0 +1,349 @@
+//! Points, directions and headings, for puzzles that move around on a plane
+//! or in space.
+//!
+//! The y axis points down, as on a screen or in a character map, so north
+//! is `(0, -1)` and turning right turns clockwise.
+
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+macro_rules! point {
+    ($(#[$attr:meta])* $name:ident { $($c:ident),+ }) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Debug)]
+        pub struct $name {
+            $(pub $c: i64),+
+        }
+
+        impl $name {
+            pub const ORIGIN: Self = Self { $($c: 0),+ };
+
+            pub const fn new($($c: i64),+) -> Self {
+                Self { $($c),+ }
+            }
+
+            /// The Manhattan distance from the origin.
+            pub fn manhattan(self) -> i64 {
+                0 $(+ self.$c.abs())+
+            }
+
+            /// The Chebyshev distance from the origin, the most steps along
+            /// any axis.
+            pub fn chebyshev(self) -> i64 {
+                0 $(.max(self.$c.abs()))+
+            }
+
+            /// Every point that differs by at most one along each axis.
+            pub fn neighbors(self) -> impl Iterator<Item = Self> {
+                let dims = [$(stringify!($c)),+].len() as u32;
+                (0..3i64.pow(dims)).filter_map(move |mut i| {
+                    let offset = Self { $($c: { let d = i % 3 - 1; i /= 3; d }),+ };
+                    (offset != Self::ORIGIN).then(|| self + offset)
+                })
+            }
+        }
+
+        impl Add for $name {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Self { $($c: self.$c + other.$c),+ }
+            }
+        }
+
+        impl Sub for $name {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                Self { $($c: self.$c - other.$c),+ }
+            }
+        }
+
+        impl Mul<i64> for $name {
+            type Output = Self;
+
+            fn mul(self, n: i64) -> Self {
+                Self { $($c: self.$c * n),+ }
+            }
+        }
+
+        impl Neg for $name {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $($c: -self.$c),+ }
+            }
+        }
+
+        impl AddAssign for $name {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $name {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+    };
+}
+
+point!(
+    /// A point on a plane.
+    Point2 { x, y }
+);
+point!(
+    /// A point in space.
+    Point3 { x, y, z }
+);
+point!(
+    /// A point in four-dimensional space.
+    Point4 { x, y, z, w }
+);
+
+impl Point2 {
+    /// Rotate a quarter turn clockwise about the origin.
+    pub fn rotate_right(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    /// Rotate a quarter turn counterclockwise about the origin.
+    pub fn rotate_left(self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+
+    /// Rotate about the origin by a number of quarter turns, clockwise if
+    /// positive.
+    pub fn rotate(self, quarter_turns: i64) -> Self {
+        match quarter_turns.rem_euclid(4) {
+            0 => self,
+            1 => self.rotate_right(),
+            2 => -self,
+            _ => self.rotate_left(),
+        }
+    }
+
+    /// The four orthogonal neighbours, clockwise from north.
+    pub fn neighbors4(self) -> [Self; 4] {
+        Dir4::ALL.map(|dir| self + dir.step())
+    }
+}
+
+impl From<(i64, i64)> for Point2 {
+    fn from((x, y): (i64, i64)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+/// One of the four compass directions.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Dir4 {
+    N,
+    E,
+    S,
+    W,
+}
+
+impl Dir4 {
+    /// Every direction, clockwise from north.
+    pub const ALL: [Self; 4] = [Dir4::N, Dir4::E, Dir4::S, Dir4::W];
+
+    /// The direction a letter or arrow stands for: `NESW`, `URDL` or `^>v<`,
+    /// in either case.
+    pub fn from_char(c: char) -> Option<Self> {
+        match c.to_ascii_uppercase() {
+            'N' | 'U' | '^' => Some(Dir4::N),
+            'E' | 'R' | '>' => Some(Dir4::E),
+            'S' | 'D' | 'V' => Some(Dir4::S),
+            'W' | 'L' | '<' => Some(Dir4::W),
+            _ => None,
+        }
+    }
+
+    /// Turn by a number of quarter turns, clockwise if positive.
+    pub fn turn(self, quarter_turns: i64) -> Self {
+        Self::ALL[(self as i64 + quarter_turns).rem_euclid(4) as usize]
+    }
+
+    pub fn turn_right(self) -> Self {
+        self.turn(1)
+    }
+
+    pub fn turn_left(self) -> Self {
+        self.turn(-1)
+    }
+
+    pub fn turn_around(self) -> Self {
+        self.turn(2)
+    }
+
+    /// A step of one in this direction.
+    pub fn step(self) -> Point2 {
+        match self {
+            Dir4::N => Point2::new(0, -1),
+            Dir4::E => Point2::new(1, 0),
+            Dir4::S => Point2::new(0, 1),
+            Dir4::W => Point2::new(-1, 0),
+        }
+    }
+}
+
+/// One of the eight compass directions, including the diagonals.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Dir8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Dir8 {
+    /// Every direction, clockwise from north.
+    pub const ALL: [Self; 8] = [
+        Dir8::N, Dir8::NE, Dir8::E, Dir8::SE, Dir8::S, Dir8::SW, Dir8::W, Dir8::NW,
+    ];
+
+    /// Turn by a number of eighth turns, clockwise if positive.
+    pub fn turn(self, eighth_turns: i64) -> Self {
+        Self::ALL[(self as i64 + eighth_turns).rem_euclid(8) as usize]
+    }
+
+    pub fn turn_right(self) -> Self {
+        self.turn(1)
+    }
+
+    pub fn turn_left(self) -> Self {
+        self.turn(-1)
+    }
+
+    pub fn turn_around(self) -> Self {
+        self.turn(4)
+    }
+
+    /// A step of one in this direction, diagonally for the diagonals.
+    pub fn step(self) -> Point2 {
+        match self {
+            Dir8::N => Point2::new(0, -1),
+            Dir8::NE => Point2::new(1, -1),
+            Dir8::E => Point2::new(1, 0),
+            Dir8::SE => Point2::new(1, 1),
+            Dir8::S => Point2::new(0, 1),
+            Dir8::SW => Point2::new(-1, 1),
+            Dir8::W => Point2::new(-1, 0),
+            Dir8::NW => Point2::new(-1, -1),
+        }
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        Dir8::ALL[dir as usize * 2]
+    }
+}
+
+/// Something at a point on a plane that faces a direction, moving forward
+/// and turning as it is told.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Turtle {
+    pub pos: Point2,
+    pub dir: Dir4,
+}
+
+impl Turtle {
+    pub fn new(pos: Point2, dir: Dir4) -> Self {
+        Self { pos, dir }
+    }
+
+    pub fn forward(&mut self, n: i64) {
+        self.pos += self.dir.step() * n;
+    }
+
+    /// Turn by a number of quarter turns, clockwise if positive.
+    pub fn turn(&mut self, quarter_turns: i64) {
+        self.dir = self.dir.turn(quarter_turns);
+    }
+
+    pub fn turn_right(&mut self) {
+        self.turn(1);
+    }
+
+    pub fn turn_left(&mut self) {
+        self.turn(-1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let p = Point2::new(3, -4);
+        assert_eq!(p + Point2::new(1, 1), Point2::new(4, -3));
+        assert_eq!(p - Point2::new(1, 1), Point2::new(2, -5));
+        assert_eq!(p * 2, Point2::new(6, -8));
+        assert_eq!(-p, Point2::new(-3, 4));
+        assert_eq!(Point3::new(1, 2, 3) + Point3::new(1, 1, 1), Point3::new(2, 3, 4));
+    }
+
+    #[test]
+    fn test_distance() {
+        assert_eq!(Point2::new(3, -4).manhattan(), 7);
+        assert_eq!(Point2::new(3, -4).chebyshev(), 4);
+        assert_eq!(Point4::new(1, -2, 3, -4).manhattan(), 10);
+        assert_eq!(Point3::new(1, -2, 0).chebyshev(), 2);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        assert_eq!(Point2::ORIGIN.neighbors().count(), 8);
+        assert_eq!(Point3::ORIGIN.neighbors().count(), 26);
+        assert_eq!(Point4::new(5, 5, 5, 5).neighbors().count(), 80);
+        assert!(Point3::ORIGIN.neighbors().all(|p| p.chebyshev() == 1));
+        assert_eq!(
+            Point2::new(1, 1).neighbors4(),
+            [Point2::new(1, 0), Point2::new(2, 1), Point2::new(1, 2), Point2::new(0, 1)],
+        );
+    }
+
+    #[test]
+    fn test_rotate() {
+        let p = Point2::new(10, -4);
+        assert_eq!(p.rotate_right(), Point2::new(4, 10));
+        assert_eq!(p.rotate_left(), Point2::new(-4, -10));
+        assert_eq!(p.rotate(2), -p);
+        assert_eq!(p.rotate(-1), p.rotate_left());
+        assert_eq!(p.rotate(4), p);
+    }
+
+    #[test]
+    fn test_dirs() {
+        assert_eq!(Dir4::N.turn_left(), Dir4::W);
+        assert_eq!(Dir4::W.turn_right(), Dir4::N);
+        assert_eq!(Dir4::E.turn_around(), Dir4::W);
+        assert_eq!(Dir4::S.turn(-6), Dir4::N);
+        assert_eq!(Dir4::E.step().rotate_right(), Dir4::S.step());
+        assert_eq!(Dir8::N.turn_left(), Dir8::NW);
+        assert_eq!(Dir8::NE.turn_around(), Dir8::SW);
+        assert_eq!(Dir8::from(Dir4::W), Dir8::W);
+
+        let dirs: Vec<Option<Dir4>> = "^>v<NeSwUL?".chars().map(Dir4::from_char).collect();
+        let [n, e, s, w] = Dir4::ALL.map(Some);
+        assert_eq!(dirs, [n, e, s, w, n, e, s, w, n, w, None]);
+    }
+
+    #[test]
+    fn test_turtle() {
+        let mut turtle = Turtle::new(Point2::ORIGIN, Dir4::E);
+        turtle.forward(10);
+        turtle.turn_left();
+        turtle.forward(3);
+        turtle.turn(-3);
+        turtle.forward(1);
+        assert_eq!(turtle, Turtle::new(Point2::new(11, -3), Dir4::E));
+    }
+}
@@ -0,0 +1,315 @@
+//! A rectangular grid of cells, for the puzzles that are played out on a
+//! map of characters.
+//!
+//! Positions are `(x, y)` pairs, with `x` the column and `y` the row counted
+//! from the top left. Steps between positions are `(dx, dy)` pairs.
+
+use std::error;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// A position in a grid, as `(x, y)`.
+pub type Pos = (usize, usize);
+
+/// A step from one position to another, as `(dx, dy)`.
+pub type Step = (isize, isize);
+
+/// The steps to the four orthogonal neighbours: up, right, down and left.
+pub const ORTHOGONAL: [Step; 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// The steps to all eight neighbours, clockwise from the top left.
+pub const ADJACENT: [Step; 8] = [(-1, -1), (0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0)];
+
+/// A character map that could not be parsed into a grid.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ParseError {
+    Empty,
+    /// A line is longer or shorter than the first one.
+    Ragged { line: usize, expected: usize, found: usize },
+    /// A character that does not stand for any cell.
+    Char { line: usize, column: usize, c: char },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::Empty => f.write_str("Empty grid"),
+            ParseError::Ragged { line, expected, found } => {
+                write!(f, "Line {} has {} cells instead of {}", line, found, expected)
+            }
+            ParseError::Char { line, column, c } => {
+                write!(f, "Unknown cell {:?} at line {}, column {}", c, line, column)
+            }
+        }
+    }
+}
+
+impl error::Error for ParseError {}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// A grid with every cell set to `fill`. The width must not be zero.
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        assert!(width > 0, "a grid needs at least one column");
+        Self { cells: vec![fill; width * height], width, height }
+    }
+
+    /// A grid with every cell set to the result of `f` for its position.
+    /// The width must not be zero.
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Pos) -> T) -> Self {
+        assert!(width > 0, "a grid needs at least one column");
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(&mut f)
+            .collect();
+        Self { cells, width, height }
+    }
+
+    /// A grid from its cells, row by row.
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            width > 0 && cells.len().is_multiple_of(width),
+            "{} cells do not fill rows of {}",
+            cells.len(),
+            width,
+        );
+        let height = cells.len() / width;
+        Self { cells, width, height }
+    }
+
+    /// Parse a character map, with a line per row. `f` gives the cell that
+    /// a character stands for, or `None` if it is not a valid cell.
+    pub fn parse(s: &str, mut f: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        let lines: Vec<&str> = s.trim_end_matches('\n').lines().collect();
+        let width = lines.first().map_or(0, |line| line.chars().count());
+        if width == 0 {
+            return Err(ParseError::Empty);
+        }
+
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for (y, line) in lines.iter().enumerate() {
+            let found = line.chars().count();
+            if found != width {
+                return Err(ParseError::Ragged { line: y + 1, expected: width, found });
+            }
+            for (x, c) in line.chars().enumerate() {
+                let cell = f(c).ok_or(ParseError::Char { line: y + 1, column: x + 1, c })?;
+                cells.push(cell);
+            }
+        }
+        Ok(Self { cells, width, height: lines.len() })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Pos) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos).then(|| &self.cells[pos.1 * self.width + pos.0])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.1 * self.width + pos.0])
+        } else {
+            None
+        }
+    }
+
+    /// The cell at a position, as if the grid repeated itself endlessly to
+    /// the right and to the bottom. The grid must not be empty, which it can
+    /// be with a height of zero.
+    pub fn get_wrapping(&self, (x, y): Pos) -> &T {
+        assert!(!self.cells.is_empty(), "an empty grid has no cells to repeat");
+        &self[(x % self.width, y % self.height)]
+    }
+
+    /// The position one step away, if it is inside the grid.
+    pub fn offset(&self, (x, y): Pos, (dx, dy): Step) -> Option<Pos> {
+        let pos = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        self.contains(pos).then_some(pos)
+    }
+
+    /// The positions one step away in each of the given directions that are
+    /// inside the grid.
+    pub fn neighbors<'a>(&'a self, pos: Pos, steps: &'a [Step]) -> impl Iterator<Item = Pos> + 'a {
+        steps.iter().filter_map(move |&step| self.offset(pos, step))
+    }
+
+    /// The orthogonal neighbours of a position.
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.neighbors(pos, &ORTHOGONAL)
+    }
+
+    /// The orthogonal and diagonal neighbours of a position.
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.neighbors(pos, &ADJACENT)
+    }
+
+    /// The cells seen looking from a position in a direction, nearest first,
+    /// up to the edge of the grid.
+    pub fn ray(&self, pos: Pos, step: Step) -> impl Iterator<Item = (Pos, &T)> + '_ {
+        assert_ne!(step, (0, 0), "a ray needs a direction");
+        let mut pos = Some(pos);
+        std::iter::from_fn(move || {
+            pos = self.offset(pos?, step);
+            pos.map(|pos| (pos, &self[pos]))
+        })
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} is outside a grid {} wide", x, self.width);
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    /// The cells, row by row.
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { cells: self.cells.iter().map(f).collect(), width: self.width, height: self.height }
+    }
+
+    /// Draw the grid with a character per cell and a line per row.
+    pub fn render(&self, mut f: impl FnMut(&T) -> char) -> String {
+        self.rows()
+            .map(|row| row.iter().map(&mut f).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): Pos) -> &T {
+        assert!(self.contains((x, y)), "{:?} is outside a {}x{} grid", (x, y), self.width, self.height);
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, (x, y): Pos) -> &mut T {
+        assert!(self.contains((x, y)), "{:?} is outside a {}x{} grid", (x, y), self.width, self.height);
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Grid<bool> {
+        Grid::parse("#..\n.#.\n..#\n#.#\n", |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = example();
+        assert_eq!((grid.width(), grid.height()), (3, 4));
+        assert!(grid[(0, 0)] && grid[(1, 1)] && !grid[(1, 0)]);
+
+        let digit = |c: char| c.to_digit(10);
+        assert_eq!(Grid::parse("", digit), Err(ParseError::Empty));
+        assert_eq!(
+            Grid::parse("12\n3\n", digit),
+            Err(ParseError::Ragged { line: 2, expected: 2, found: 1 }),
+        );
+        assert_eq!(Grid::parse("12\n3x\n", digit), Err(ParseError::Char { line: 2, column: 2, c: 'x' }));
+    }
+
+    #[test]
+    fn test_access() {
+        let grid = example();
+        assert_eq!(grid.get((2, 2)), Some(&true));
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get_wrapping((5, 6)), &true);
+        assert_eq!(grid.offset((0, 0), (-1, 0)), None);
+        assert_eq!(grid.offset((0, 0), (1, 2)), Some((1, 2)));
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = example();
+        assert_eq!(grid.neighbors4((0, 0)).collect::<Vec<_>>(), [(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbors8((0, 0)).collect::<Vec<_>>(), [(1, 0), (1, 1), (0, 1)]);
+        assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn test_ray() {
+        let grid = example();
+        let ray: Vec<(Pos, bool)> = grid.ray((0, 0), (1, 1)).map(|(pos, &c)| (pos, c)).collect();
+        assert_eq!(ray, [((1, 1), true), ((2, 2), true)]);
+        assert_eq!(grid.ray((0, 3), (0, 1)).count(), 0);
+    }
+
+    #[test]
+    fn test_views() {
+        let grid = Grid::from_fn(3, 2, |(x, y)| x + 10 * y);
+        assert_eq!(grid.row(1), [10, 11, 12]);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), [2, 12]);
+        assert_eq!(grid.iter().nth(4), Some(((1, 1), &11)));
+        assert_eq!(grid.map(|&n| n % 2 == 0), Grid::from_vec(3, vec![true, false, true, true, false, true]));
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one column")]
+    fn test_zero_width() {
+        Grid::from_fn(0, 3, |_| 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "an empty grid has no cells to repeat")]
+    fn test_wrapping_empty() {
+        Grid::new(3, 0, 0).get_wrapping((4, 5));
+    }
+
+    #[test]
+    fn test_render() {
+        assert_eq!(example().render(|&c| if c { '#' } else { '.' }), "#..\n.#.\n..#\n#.#");
+    }
+}
@@ -0,0 +1,240 @@
+//! Number theory for the puzzles about periods and remainders: greatest
+//! common divisors, modular arithmetic and the Chinese remainder theorem.
+//!
+//! The modular functions work on `i64` and multiply in `i128`, so they do
+//! not overflow for any modulus that fits in an `i64`.
+
+use std::ops::{Div, Mul, Rem};
+
+/// The integer types that [`gcd`] and [`lcm`] work on.
+pub trait Integer: Copy + PartialEq + Rem<Output = Self> + Div<Output = Self> + Mul<Output = Self> {
+    const ZERO: Self;
+
+    /// The absolute value, which is the value itself for unsigned types.
+    fn abs(self) -> Self;
+}
+
+macro_rules! integer {
+    (signed: $($t:ty),*; unsigned: $($u:ty),*) => {
+        $(impl Integer for $t {
+            const ZERO: Self = 0;
+
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+        })*
+        $(impl Integer for $u {
+            const ZERO: Self = 0;
+
+            fn abs(self) -> Self {
+                self
+            }
+        })*
+    };
+}
+
+integer!(signed: i8, i16, i32, i64, i128, isize; unsigned: u8, u16, u32, u64, u128, usize);
+
+/// The greatest common divisor, which is never negative. The divisor of
+/// zero and `n` is `n`.
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// The least common multiple, which is never negative. The multiple of zero
+/// and anything is zero.
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    if a == T::ZERO || b == T::ZERO {
+        return T::ZERO;
+    }
+    (a / gcd(a, b) * b).abs()
+}
+
+/// The greatest common divisor of all the numbers, or `None` if there are
+/// none.
+pub fn gcd_all<T: Integer>(ns: &[T]) -> Option<T> {
+    ns.iter().copied().reduce(gcd).map(T::abs)
+}
+
+/// The least common multiple of all the numbers, or `None` if there are
+/// none.
+pub fn lcm_all<T: Integer>(ns: &[T]) -> Option<T> {
+    ns.iter().copied().reduce(lcm).map(T::abs)
+}
+
+/// The extended Euclidean algorithm: `(g, x, y)` with `g = gcd(a, b)` and
+/// `a * x + b * y = g`.
+pub fn egcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut r0, mut r1) = (a, b);
+    let (mut x0, mut x1) = (1, 0);
+    let (mut y0, mut y1) = (0, 1);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (x0, x1) = (x1, x0 - q * x1);
+        (y0, y1) = (y1, y0 - q * y1);
+    }
+    if r0 < 0 {
+        (-r0, -x0, -y0)
+    } else {
+        (r0, x0, y0)
+    }
+}
+
+/// `a * b` modulo `m`, in `0..m`.
+pub fn mod_mul(a: i64, b: i64, m: i64) -> i64 {
+    (a as i128 * b as i128).rem_euclid(m as i128) as i64
+}
+
+/// `base` to the power `exp`, modulo `m`, in `0..m`.
+pub fn mod_pow(base: i64, mut exp: u64, m: i64) -> i64 {
+    let mut base = base.rem_euclid(m);
+    let mut result = 1 % m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mod_mul(result, base, m);
+        }
+        base = mod_mul(base, base, m);
+        exp >>= 1;
+    }
+    result
+}
+
+/// The inverse of `a` modulo `m`, in `0..m`, or `None` if `a` and `m` are
+/// not coprime.
+pub fn mod_inv(a: i64, m: i64) -> Option<i64> {
+    let (g, x, _) = egcd(a.rem_euclid(m), m);
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+/// Solve a system of congruences `x ≡ r (mod m)`, given as `(r, m)` pairs,
+/// with the Chinese remainder theorem.
+///
+/// The moduli do not have to be coprime. The solution is `(x, m)`, where
+/// `x` is the smallest non-negative solution and `m` is the least common
+/// multiple of the moduli, so that every `x + k * m` is a solution as
+/// well. `None` means the congruences contradict each other.
+///
+/// # Panics
+///
+/// If a modulus is not positive, or if the least common multiple of the
+/// moduli does not fit in an `i64`.
+pub fn crt(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    let (mut x, mut m) = (0i128, 1i128);
+    for &(r, n) in congruences {
+        assert!(n > 0, "modulus {} is not positive", n);
+        let (r, n) = (r as i128, n as i128);
+        let g = gcd(m, n);
+        if (r - x) % g != 0 {
+            return None;
+        }
+
+        // x + m * k ≡ r (mod n), so k ≡ (r - x) / g * (m / g)^-1 (mod n / g)
+        let step = n / g;
+        let inv = mod_inv((m / g % step) as i64, step as i64)
+            .expect("m / g and n / g are coprime") as i128;
+        let k = ((r - x) / g % step * inv).rem_euclid(step);
+        x += m * k;
+        // Both fit in an i64, so their product fits in an i128
+        m *= step;
+        assert!(m <= i64::MAX as i128, "the combined modulus does not fit in an i64");
+        x = x.rem_euclid(m);
+    }
+    Some((x as i64, m as i64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(-12i32, 18), 6);
+        assert_eq!(gcd(0u64, 7), 7);
+        assert_eq!(gcd(0, 0), 0);
+        assert_eq!(gcd_all(&[24, 60, -36]), Some(12));
+        assert_eq!(gcd_all::<u8>(&[]), None);
+    }
+
+    #[test]
+    fn test_lcm() {
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(-4i64, 6), 12);
+        assert_eq!(lcm(0, 6), 0);
+        // The periods of the example moons of 2019 day 12
+        assert_eq!(lcm_all(&[2028u64, 5898, 4702]), Some(4686774924));
+        assert_eq!(lcm_all(&[7usize]), Some(7));
+    }
+
+    #[test]
+    fn test_egcd() {
+        for (a, b) in [(240, 46), (46, 240), (-240, 46), (17, 0), (0, -5)] {
+            let (g, x, y) = egcd(a, b);
+            assert_eq!(g, gcd(a, b));
+            assert_eq!(a * x + b * y, g, "egcd({}, {})", a, b);
+        }
+    }
+
+    #[test]
+    fn test_modular() {
+        assert_eq!(mod_inv(3, 11), Some(4));
+        assert_eq!(mod_inv(-3, 11), Some(7));
+        assert_eq!(mod_inv(6, 9), None);
+        assert_eq!(mod_pow(4, 13, 497), 445);
+        assert_eq!(mod_pow(-2, 3, 5), 2);
+        assert_eq!(mod_pow(5, 0, 1), 0);
+
+        // Products past i64::MAX
+        let p = 1_000_000_000_000_000_003;
+        assert_eq!(mod_mul(p - 1, p - 1, p), 1);
+        assert_eq!(mod_pow(2, p as u64 - 1, p), 1);
+        assert_eq!(mod_mul(mod_inv(123_456_789, p).unwrap(), 123_456_789, p), 1);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt(&[(-1, 3), (13, 5)]), Some((8, 15)));
+        assert_eq!(crt(&[]), Some((0, 1)));
+
+        // Moduli that are not coprime
+        assert_eq!(crt(&[(3, 4), (1, 6)]), Some((7, 12)));
+        assert_eq!(crt(&[(3, 4), (2, 6)]), None);
+
+        // The buses of 2020 day 13, where bus `id` leaves `offset` minutes
+        // after the answer
+        let buses = [(7, 0), (13, 1), (59, 4), (31, 6), (19, 7)];
+        let congruences: Vec<(i64, i64)> = buses.iter().map(|&(id, offset)| (-offset, id)).collect();
+        assert_eq!(crt(&congruences), Some((1068781, 3162341)));
+
+        // Moduli whose products along the way overflow an i64
+        let (a, b) = (1_000_000_007, 998_244_353);
+        let (x, m) = crt(&[(5, a), (7, b)]).unwrap();
+        assert_eq!((x % a, x % b, m), (5, 7, a * b));
+    }
+
+    #[test]
+    #[should_panic(expected = "modulus -5 is not positive")]
+    fn test_crt_negative_modulus() {
+        crt(&[(1, 3), (2, -5)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "modulus 0 is not positive")]
+    fn test_crt_zero_modulus() {
+        crt(&[(1, 0)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "does not fit in an i64")]
+    fn test_crt_too_large() {
+        // The product already overflows an i64 before the last congruence
+        let (a, b, c) = (1_000_000_007, 998_244_353, 1_000_000_009);
+        crt(&[(1, a), (2, b), (3, c), (4, 7)]);
+    }
+}
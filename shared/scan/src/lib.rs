@@ -0,0 +1,368 @@
+//! Taking puzzle input apart into typed values, with errors that point to
+//! the line and column where the input is not what it should be.
+//!
+//! [`scan!`] matches a line against a pattern like `"{} can fly {} km/s"`
+//! and parses the fields between the literal text. [`lines`], [`words`],
+//! [`list`] and [`blocks`] split up larger pieces of input, and move the
+//! errors of the parts to where those parts are in the whole.
+//!
+//! Lines and columns count from 1, and columns count characters.
+
+use std::any;
+use std::error;
+use std::fmt;
+
+/// Where and how the input is not what it should be.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Error {
+    pub line: usize,
+    pub column: usize,
+    pub kind: ErrorKind,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ErrorKind {
+    /// The literal text of a pattern is missing.
+    Expected(String),
+    /// A field that does not parse as the type it should have.
+    Invalid { found: String, expected: &'static str },
+    /// Text after the end of a pattern.
+    Trailing(String),
+}
+
+impl Error {
+    fn new(column: usize, kind: ErrorKind) -> Self {
+        Error { line: 1, column, kind }
+    }
+
+    /// Move the error from a piece of input to where the piece starts in the
+    /// whole, `lines` lines and `columns` characters further.
+    fn offset(mut self, lines: usize, columns: usize) -> Self {
+        if self.line == 1 {
+            self.column += columns;
+        }
+        self.line += lines;
+        self
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (line, column) = (self.line, self.column);
+        match &self.kind {
+            ErrorKind::Expected(text) => write!(f, "Expected {:?} at line {}, column {}", text, line, column),
+            ErrorKind::Invalid { found, expected } => {
+                write!(f, "Expected {} at line {}, column {}, found {:?}", expected, line, column, found)
+            }
+            ErrorKind::Trailing(text) => write!(f, "Unexpected {:?} at line {}, column {}", text, line, column),
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+/// The parsed value, or a panic with the message of the error, which tells
+/// the line and column. For solutions, which have no way to return errors.
+pub fn or_panic<T>(result: Result<T, Error>) -> T {
+    result.unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// The types that a field of the input parses as.
+pub trait FromField<'a>: Sized {
+    fn from_field(s: &'a str) -> Option<Self>;
+}
+
+macro_rules! from_str {
+    ($($t:ty),*) => {
+        $(impl FromField<'_> for $t {
+            fn from_field(s: &str) -> Option<Self> {
+                s.parse().ok()
+            }
+        })*
+    };
+}
+
+from_str!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64, bool, char, String);
+
+impl<'a> FromField<'a> for &'a str {
+    fn from_field(s: &'a str) -> Option<Self> {
+        Some(s)
+    }
+}
+
+/// A piece of a line that a pattern matched.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Field<'a> {
+    pub text: &'a str,
+    pub column: usize,
+}
+
+impl<'a> Field<'a> {
+    pub fn parse<T: FromField<'a>>(&self) -> Result<T, Error> {
+        T::from_field(self.text).ok_or_else(|| {
+            let kind = ErrorKind::Invalid { found: self.text.to_owned(), expected: any::type_name::<T>() };
+            Error::new(self.column, kind)
+        })
+    }
+}
+
+/// The column of a byte offset in a line.
+fn column(s: &str, offset: usize) -> usize {
+    s[..offset].chars().count() + 1
+}
+
+/// Match a line against a pattern, where every `{}` is a field that takes
+/// the text up to the first match of the literal text after it. A field at
+/// the end of the pattern takes the rest of the line. When the literal text
+/// after a field is missing, the error points to the start of the field.
+///
+/// [`scan!`] parses the fields as well.
+///
+/// # Panics
+///
+/// If two fields follow each other without literal text between them.
+pub fn scan<'a>(s: &'a str, pattern: &str) -> Result<Vec<Field<'a>>, Error> {
+    let mut literals = pattern.split("{}");
+    let first = literals.next().unwrap_or("");
+    if !s.starts_with(first) {
+        return Err(Error::new(1, ErrorKind::Expected(first.to_owned())));
+    }
+
+    let mut fields = Vec::new();
+    let mut pos = first.len();
+    let mut literals = literals.peekable();
+    while let Some(literal) = literals.next() {
+        let len = match (literal, literals.peek()) {
+            ("", None) => s.len() - pos,
+            ("", Some(_)) => panic!("fields without text between them in {:?}", pattern),
+            _ => s[pos..]
+                .find(literal)
+                .ok_or_else(|| Error::new(column(s, pos), ErrorKind::Expected(literal.to_owned())))?,
+        };
+        fields.push(Field { text: &s[pos..pos + len], column: column(s, pos) });
+        pos += len + literal.len();
+    }
+
+    if pos < s.len() {
+        return Err(Error::new(column(s, pos), ErrorKind::Trailing(s[pos..].to_owned())));
+    }
+    Ok(fields)
+}
+
+/// Match a line against a pattern with [`scan()`](fn@scan), and parse its
+/// fields as the given types into a tuple.
+///
+/// ```
+/// let (name, speed) = scan::scan!("Comet can fly 14 km/s", "{} can fly {} km/s" => &str, u32).unwrap();
+/// assert_eq!((name, speed), ("Comet", 14));
+/// ```
+#[macro_export]
+macro_rules! scan {
+    ($s:expr, $pattern:expr => $($t:ty),+ $(,)?) => {
+        $crate::scan($s, $pattern).and_then(|fields| {
+            assert_eq!(fields.len(), [$(stringify!($t)),+].len(), "the number of fields and types differ");
+            let mut fields = fields.iter();
+            Ok(($(fields.next().unwrap().parse::<$t>()?,)+))
+        })
+    };
+}
+
+/// Parse every line.
+pub fn lines<'a, T>(s: &'a str, mut f: impl FnMut(&'a str) -> Result<T, Error>) -> Result<Vec<T>, Error> {
+    s.lines()
+        .enumerate()
+        .map(|(i, line)| f(line).map_err(|e| e.offset(i, 0)))
+        .collect()
+}
+
+/// The pieces of every line between separators, with their line and column
+/// offsets.
+fn pieces(s: &str, separator: fn(char) -> bool) -> impl Iterator<Item = (usize, usize, &str)> {
+    s.lines().enumerate().flat_map(move |(i, line)| {
+        line.split(separator)
+            .filter(|piece| !piece.is_empty())
+            .map(move |piece| {
+                let offset = piece.as_ptr() as usize - line.as_ptr() as usize;
+                (i, column(line, offset) - 1, piece)
+            })
+    })
+}
+
+/// Parse every word, where words are separated by whitespace and can be on
+/// different lines.
+pub fn words<'a, T>(s: &'a str, mut f: impl FnMut(&'a str) -> Result<T, Error>) -> Result<Vec<T>, Error> {
+    pieces(s, char::is_whitespace)
+        .map(|(lines, columns, word)| f(word).map_err(|e| e.offset(lines, columns)))
+        .collect()
+}
+
+/// Parse a list of values separated by commas, whitespace or both, like
+/// `1,2,3` or `22 13 17`.
+pub fn list<'a, T: FromField<'a>>(s: &'a str) -> Result<Vec<T>, Error> {
+    pieces(s, |c| c == ',' || c.is_whitespace())
+        .map(|(lines, columns, text)| Field { text, column: 1 }.parse().map_err(|e| e.offset(lines, columns)))
+        .collect()
+}
+
+/// A group of lines from [`blocks`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Block<'a> {
+    pub text: &'a str,
+    /// The line the block starts at.
+    pub line: usize,
+}
+
+impl<'a> Block<'a> {
+    /// Parse the block, moving any error to where it is in the whole input.
+    pub fn parse<T>(&self, f: impl FnOnce(&'a str) -> Result<T, Error>) -> Result<T, Error> {
+        f(self.text).map_err(|e| e.offset(self.line - 1, 0))
+    }
+}
+
+/// The groups of lines from [`blocks`].
+#[derive(Clone, Debug)]
+pub struct Blocks<'a> {
+    rest: &'a str,
+    line: usize,
+}
+
+/// The groups of lines that are separated by one or more blank lines, which
+/// are empty or only have whitespace. The blocks do not include the line
+/// ending of their last line.
+pub fn blocks(s: &str) -> Blocks<'_> {
+    Blocks { rest: s, line: 1 }
+}
+
+/// The first line without its line ending, and the text after it.
+fn split_line(s: &str) -> (&str, &str) {
+    let (line, rest) = s.split_once('\n').unwrap_or((s, ""));
+    (line.strip_suffix('\r').unwrap_or(line), rest)
+}
+
+impl<'a> Iterator for Blocks<'a> {
+    type Item = Block<'a>;
+
+    fn next(&mut self) -> Option<Block<'a>> {
+        // Skip the blank lines before the block
+        loop {
+            if self.rest.is_empty() {
+                return None;
+            }
+            let (line, rest) = split_line(self.rest);
+            if !line.trim().is_empty() {
+                break;
+            }
+            self.rest = rest;
+            self.line += 1;
+        }
+
+        let (start, line) = (self.rest, self.line);
+        let mut len = 0;
+        while !self.rest.is_empty() {
+            let (text, rest) = split_line(self.rest);
+            if text.trim().is_empty() {
+                break;
+            }
+            len = text.as_ptr() as usize + text.len() - start.as_ptr() as usize;
+            self.rest = rest;
+            self.line += 1;
+        }
+        Some(Block { text: &start[..len], line })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REINDEER: &str = "{} can fly {} km/s for {} seconds, but then must rest for {} seconds.";
+
+    #[test]
+    fn test_scan() {
+        let line = "Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds.";
+        assert_eq!(scan!(line, REINDEER => &str, u32, u32, u32), Ok(("Comet", 14, 10, 127)));
+        assert_eq!(scan!("x=-3, y=4", "x={}, y={}" => i32, i32), Ok((-3, 4)));
+        assert_eq!(scan!("42", "{}" => u8), Ok((42,)));
+        assert_eq!(scan!("a-b", "{}-{}" => char, String), Ok(('a', "b".to_owned())));
+
+        let fields = scan("move 3 from 1 to 2", "move {} from {} to {}").unwrap();
+        assert_eq!(fields.iter().map(|f| f.column).collect::<Vec<_>>(), [6, 13, 18]);
+    }
+
+    #[test]
+    fn test_scan_errors() {
+        let error = |column, kind| Error { line: 1, column, kind };
+        assert_eq!(
+            scan!("Comet can fly fast km/s", "{} can fly {} km/s" => &str, u32).unwrap_err(),
+            error(15, ErrorKind::Invalid { found: "fast".to_owned(), expected: "u32" })
+        );
+        assert_eq!(
+            scan!("Comet can run 14 km/s", "{} can fly {} km/s" => &str, u32).unwrap_err(),
+            error(1, ErrorKind::Expected(" can fly ".to_owned()))
+        );
+        assert_eq!(
+            scan!("Comet can fly 14 km/h", "{} can fly {} km/s" => &str, u32).unwrap_err(),
+            error(15, ErrorKind::Expected(" km/s".to_owned()))
+        );
+        assert_eq!(
+            scan!("x=1, y=2, z=3", "x={}, y={}," => i32, i32).unwrap_err(),
+            error(10, ErrorKind::Trailing(" z=3".to_owned()))
+        );
+        assert_eq!(scan!("y=1", "x={}" => i32).unwrap_err(), error(1, ErrorKind::Expected("x=".to_owned())));
+        // Columns count characters rather than bytes
+        assert_eq!(
+            scan!("é: x", "{}: {}" => &str, u8).unwrap_err(),
+            error(4, ErrorKind::Invalid { found: "x".to_owned(), expected: "u8" })
+        );
+    }
+
+    #[test]
+    fn test_lists() {
+        assert_eq!(list::<u32>("7,4,9,5"), Ok(vec![7, 4, 9, 5]));
+        assert_eq!(list::<i64>(" 22 13  17\n-8, 2"), Ok(vec![22, 13, 17, -8, 2]));
+        assert_eq!(list::<u8>(""), Ok(vec![]));
+        assert_eq!(
+            list::<u32>("1 2\n3 x 5"),
+            Err(Error { line: 2, column: 3, kind: ErrorKind::Invalid { found: "x".to_owned(), expected: "u32" } })
+        );
+
+        let rows = lines("1 2\n3 4", list::<u8>);
+        assert_eq!(rows, Ok(vec![vec![1, 2], vec![3, 4]]));
+
+        let pairs = words("a:1 b:2\n  c:x", |word| scan!(word, "{}:{}" => &str, u8));
+        assert_eq!(
+            pairs,
+            Err(Error { line: 2, column: 5, kind: ErrorKind::Invalid { found: "x".to_owned(), expected: "u8" } })
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected u8 at line 2, column 3, found \"x\"")]
+    fn test_or_panic() {
+        or_panic(lines("1\n2 x", list::<u8>));
+    }
+
+    #[test]
+    fn test_blocks() {
+        let input = "7,4,9\n\n1 2\n3 4\n\n5 6\n7 x\n";
+        let all: Vec<Block> = blocks(input).collect();
+        assert_eq!(all.iter().map(|b| (b.text, b.line)).collect::<Vec<_>>(), [
+            ("7,4,9", 1),
+            ("1 2\n3 4", 3),
+            ("5 6\n7 x", 6),
+        ]);
+
+        assert_eq!(all[1].parse(list::<u8>), Ok(vec![1, 2, 3, 4]));
+        let error = all[2].parse(list::<u8>).unwrap_err();
+        assert_eq!((error.line, error.column), (7, 3));
+        assert_eq!(error.to_string(), "Expected u8 at line 7, column 3, found \"x\"");
+        assert_eq!(blocks("").count(), 0);
+        assert_eq!(blocks("\n \n").count(), 0);
+
+        // Several blank lines in a row, and Windows line endings
+        let input = "\n1 2\n\n\n \n3\r\n4\r\n\r\n5\r\n";
+        let all: Vec<(&str, usize)> = blocks(input).map(|b| (b.text, b.line)).collect();
+        assert_eq!(all, [("1 2", 2), ("3\r\n4", 6), ("5", 9)]);
+        assert_eq!(blocks(input).nth(1).unwrap().parse(list::<u8>), Ok(vec![3, 4]));
+    }
+}
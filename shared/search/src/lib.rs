@@ -0,0 +1,412 @@
+//! Searching graphs that are given by a function from a node to its
+//! neighbours, like the positions on a map or the states of a puzzle.
+//!
+//! Nodes are cloned into the maps that keep track of the search, so small
+//! `Copy` nodes like positions work best. Costs are anything that adds up
+//! and orders, starting from `C::default()` as zero.
+
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+pub mod tsp;
+
+/// A path through a graph, from the start to the end.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Path<N, C> {
+    /// The nodes on the path, including the start and the end.
+    pub nodes: Vec<N>,
+    pub cost: C,
+}
+
+impl<N, C> Path<N, C> {
+    pub fn start(&self) -> &N {
+        &self.nodes[0]
+    }
+
+    pub fn end(&self) -> &N {
+        &self.nodes[self.nodes.len() - 1]
+    }
+
+    /// The number of steps on the path.
+    pub fn len(&self) -> usize {
+        self.nodes.len() - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// The nodes a search reached, with the cost of the cheapest path to each
+/// and the node it was reached from.
+#[derive(Clone, Debug)]
+pub struct Reached<N, C> {
+    nodes: HashMap<N, (C, Option<N>)>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Reached<N, C> {
+    fn new(start: N, zero: C) -> Self {
+        Self { nodes: HashMap::from([(start, (zero, None))]) }
+    }
+
+    pub fn contains(&self, node: &N) -> bool {
+        self.nodes.contains_key(node)
+    }
+
+    /// The cost of the cheapest path found to a node.
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.nodes.get(node).map(|&(cost, _)| cost)
+    }
+
+    /// Every node reached, with its cost, in no particular order.
+    pub fn costs(&self) -> impl Iterator<Item = (&N, C)> {
+        self.nodes.iter().map(|(node, &(cost, _))| (node, cost))
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// The cheapest path found from the start to a node.
+    pub fn path_to(&self, node: &N) -> Option<Path<N, C>> {
+        let cost = self.cost(node)?;
+        let mut nodes = vec![node.clone()];
+        while let Some((_, Some(parent))) = self.nodes.get(&nodes[nodes.len() - 1]) {
+            nodes.push(parent.clone());
+        }
+        nodes.reverse();
+        Some(Path { nodes, cost })
+    }
+}
+
+/// Breadth-first search for the shortest path to a node that satisfies
+/// `goal`, where every step costs one.
+pub fn bfs<N, I>(
+    start: N,
+    neighbors: impl FnMut(&N) -> I,
+    goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let (reached, end) = breadth_first(start, neighbors, goal);
+    reached.path_to(&end?)
+}
+
+/// Every node that can be reached from the start, with its distance.
+pub fn bfs_all<N, I>(start: N, neighbors: impl FnMut(&N) -> I) -> Reached<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    breadth_first(start, neighbors, |_| false).0
+}
+
+fn breadth_first<N, I>(
+    start: N,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut goal: impl FnMut(&N) -> bool,
+) -> (Reached<N, usize>, Option<N>)
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut reached = Reached::new(start.clone(), 0);
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    while let Some((node, dist)) = queue.pop_front() {
+        if goal(&node) {
+            return (reached, Some(node));
+        }
+        for next in neighbors(&node) {
+            if let Entry::Vacant(entry) = reached.nodes.entry(next.clone()) {
+                entry.insert((dist + 1, Some(node.clone())));
+                queue.push_back((next, dist + 1));
+            }
+        }
+    }
+
+    (reached, None)
+}
+
+/// Shortest path between two nodes, searching from both ends at once.
+///
+/// This only works for graphs where every step can be taken back, so that
+/// the neighbours of a node are also the nodes it can be reached from.
+pub fn bidirectional_bfs<N, I>(
+    start: N,
+    end: N,
+    mut neighbors: impl FnMut(&N) -> I,
+) -> Option<Path<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut forward = Reached::new(start.clone(), 0);
+    let mut backward = Reached::new(end.clone(), 0);
+    let mut forward_layer = vec![start.clone()];
+    let mut backward_layer = vec![end];
+
+    let mut meeting = forward.contains(&backward_layer[0]).then_some(start);
+    while meeting.is_none() && !forward_layer.is_empty() && !backward_layer.is_empty() {
+        // Grow the smaller side by a whole layer, and keep the best of the
+        // paths that meet in it.
+        let (reached, other, layer) = if forward_layer.len() <= backward_layer.len() {
+            (&mut forward, &backward, &mut forward_layer)
+        } else {
+            (&mut backward, &forward, &mut backward_layer)
+        };
+
+        let mut best = None;
+        for node in std::mem::take(layer) {
+            let dist = reached.cost(&node).unwrap() + 1;
+            for next in neighbors(&node) {
+                if let Entry::Vacant(entry) = reached.nodes.entry(next.clone()) {
+                    entry.insert((dist, Some(node.clone())));
+                    if let Some(rest) = other.cost(&next) {
+                        if best.as_ref().is_none_or(|&(len, _)| dist + rest < len) {
+                            best = Some((dist + rest, next.clone()));
+                        }
+                    }
+                    layer.push(next);
+                }
+            }
+        }
+        meeting = best.map(|(_, node)| node);
+    }
+
+    let meeting = meeting?;
+    let mut path = forward.path_to(&meeting)?;
+    let back = backward.path_to(&meeting)?;
+    path.nodes.extend(back.nodes.into_iter().rev().skip(1));
+    path.cost += back.cost;
+    Some(path)
+}
+
+/// An entry in the queue of a best-first search, ordered so that the
+/// cheapest estimate comes out of the heap first.
+struct Queued<N, C> {
+    estimate: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> Ord for Queued<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.cmp(&self.estimate)
+            .then_with(|| self.cost.cmp(&other.cost))
+    }
+}
+
+impl<N, C: Ord> PartialOrd for Queued<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> PartialEq for Queued<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<N, C: Ord> Eq for Queued<N, C> {}
+
+/// Dijkstra's algorithm for the cheapest path to a node that satisfies
+/// `goal`. `neighbors` gives the next nodes with the cost of the step.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    neighbors: impl FnMut(&N) -> I,
+    goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, neighbors, |_| C::default(), goal)
+}
+
+/// Every node that can be reached from the start, with the cost of the
+/// cheapest path to it.
+pub fn dijkstra_all<N, C, I>(start: N, neighbors: impl FnMut(&N) -> I) -> Reached<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    best_first(start, neighbors, |_| C::default(), |_| false).0
+}
+
+/// A* search for the cheapest path to a node that satisfies `goal`.
+///
+/// `heuristic` estimates the cost from a node to the nearest goal. The path
+/// is the cheapest one as long as it never overestimates.
+pub fn astar<N, C, I>(
+    start: N,
+    neighbors: impl FnMut(&N) -> I,
+    heuristic: impl FnMut(&N) -> C,
+    goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let (reached, end) = best_first(start, neighbors, heuristic, goal);
+    reached.path_to(&end?)
+}
+
+fn best_first<N, C, I>(
+    start: N,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut goal: impl FnMut(&N) -> bool,
+) -> (Reached<N, C>, Option<N>)
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let zero = C::default();
+    let mut reached = Reached::new(start.clone(), zero);
+    let mut queue = BinaryHeap::from([Queued { estimate: heuristic(&start), cost: zero, node: start }]);
+
+    while let Some(Queued { cost, node, .. }) = queue.pop() {
+        if reached.cost(&node).is_some_and(|best| cost > best) {
+            continue;
+        }
+        if goal(&node) {
+            return (reached, Some(node));
+        }
+
+        for (next, step) in neighbors(&node) {
+            let cost = cost + step;
+            if reached.cost(&next).is_none_or(|best| cost < best) {
+                reached.nodes.insert(next.clone(), (cost, Some(node.clone())));
+                queue.push(Queued { estimate: cost + heuristic(&next), cost, node: next });
+            }
+        }
+    }
+
+    (reached, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A maze with walls, where `S` is the start and `E` the end.
+    const MAZE: &str = "\
+S..#....
+.#.#.##.
+.#...#..
+.####.#.
+......#E";
+
+    type Pos = (usize, usize);
+
+    fn find(c: char) -> Pos {
+        MAZE.lines()
+            .enumerate()
+            .find_map(|(y, line)| line.find(c).map(|x| (x, y)))
+            .unwrap()
+    }
+
+    fn open((x, y): Pos) -> Vec<Pos> {
+        let rows: Vec<&[u8]> = MAZE.lines().map(str::as_bytes).collect();
+        [(x.wrapping_sub(1), y), (x + 1, y), (x, y.wrapping_sub(1)), (x, y + 1)]
+            .into_iter()
+            .filter(|&(x, y)| rows.get(y).and_then(|row| row.get(x)).is_some_and(|&c| c != b'#'))
+            .collect()
+    }
+
+    fn is_step(path: &[Pos]) -> bool {
+        path.windows(2).all(|w| open(w[0]).contains(&w[1]))
+    }
+
+    #[test]
+    fn test_bfs() {
+        let (start, end) = (find('S'), find('E'));
+        let path = bfs(start, |&pos| open(pos), |&pos| pos == end).unwrap();
+        assert_eq!(path.cost, 15);
+        assert_eq!(path.len(), 15);
+        assert_eq!((path.start(), path.end()), (&start, &end));
+        assert!(is_step(&path.nodes));
+
+        assert_eq!(bfs(start, |&pos| open(pos), |&pos| pos == (3, 0)), None);
+        assert_eq!(bfs(start, |&pos| open(pos), |&pos| pos == start).unwrap().nodes, [start]);
+    }
+
+    #[test]
+    fn test_bfs_all() {
+        let reached = bfs_all(find('S'), |&pos| open(pos));
+        assert_eq!(reached.len(), MAZE.chars().filter(|&c| ".SE".contains(c)).count());
+        assert_eq!(reached.cost(&find('E')), Some(15));
+        assert_eq!(reached.cost(&(3, 0)), None);
+        assert_eq!(reached.path_to(&(4, 0)).unwrap().cost, 8);
+    }
+
+    #[test]
+    fn test_bidirectional_bfs() {
+        let (start, end) = (find('S'), find('E'));
+        let path = bidirectional_bfs(start, end, |&pos| open(pos)).unwrap();
+        assert_eq!(path.cost, 15);
+        assert_eq!(path.nodes.len(), 16);
+        assert_eq!((path.start(), path.end()), (&start, &end));
+        assert!(is_step(&path.nodes));
+
+        assert_eq!(bidirectional_bfs(start, (20, 20), |&pos| open(pos)), None);
+        assert_eq!(bidirectional_bfs(end, end, |&pos| open(pos)).unwrap().nodes, [end]);
+
+        // Paths of every length, both odd and even
+        for (&node, dist) in bfs_all(start, |&pos| open(pos)).costs() {
+            let path = bidirectional_bfs(start, node, |&pos| open(pos)).unwrap();
+            assert_eq!(path.cost, dist, "{:?}", node);
+            assert!(is_step(&path.nodes));
+        }
+    }
+
+    /// Walking right costs one, walking down costs ten, and `E` is the end.
+    fn weighted(pos: Pos) -> Vec<(Pos, u32)> {
+        open(pos)
+            .into_iter()
+            .map(|next| (next, if next.1 == pos.1 { 1 } else { 10 }))
+            .collect()
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let (start, end) = (find('S'), find('E'));
+        let path = dijkstra(start, |&pos| weighted(pos), |&pos| pos == end).unwrap();
+        assert_eq!(path.cost, 87);
+        assert!(is_step(&path.nodes));
+
+        let reached = dijkstra_all(start, |&pos| weighted(pos));
+        assert_eq!(reached.cost(&end), Some(87));
+        assert_eq!(reached.path_to(&end).unwrap().cost, 87);
+    }
+
+    #[test]
+    fn test_astar() {
+        let (start, end) = (find('S'), find('E'));
+        let manhattan = |&(x, y): &Pos| (end.0.abs_diff(x) + end.1.abs_diff(y)) as u32;
+        let path = astar(start, |&pos| weighted(pos), manhattan, |&pos| pos == end).unwrap();
+        assert_eq!(path.cost, 87);
+        assert!(is_step(&path.nodes));
+
+        let mut expanded = 0;
+        astar(start, |&pos| { expanded += 1; weighted(pos) }, manhattan, |&pos| pos == end);
+        let mut plain = 0;
+        dijkstra(start, |&pos| { plain += 1; weighted(pos) }, |&pos| pos == end);
+        assert!(expanded <= plain);
+    }
+}
@@ -0,0 +1,242 @@
+//! The best route through every node of a small graph, with the Held-Karp
+//! algorithm.
+//!
+//! The time is `O(2^n n^2)` and the memory `O(2^n n)` for `n` nodes, which
+//! is fine up to about twenty nodes.
+
+use std::ops::Add;
+
+/// A distance matrix between named nodes. Pairs that have no distance set
+/// cannot follow each other on a route.
+#[derive(Clone, Debug)]
+pub struct Distances<W> {
+    names: Vec<String>,
+    weights: Vec<Vec<Option<W>>>,
+}
+
+impl<W> Default for Distances<W> {
+    fn default() -> Self {
+        Self { names: Vec::new(), weights: Vec::new() }
+    }
+}
+
+impl<W: Copy> Distances<W> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The index of a node, adding it if it is new.
+    pub fn node(&mut self, name: &str) -> usize {
+        if let Some(i) = self.names.iter().position(|n| n == name) {
+            return i;
+        }
+        self.names.push(name.to_owned());
+        for row in &mut self.weights {
+            row.push(None);
+        }
+        self.weights.push(vec![None; self.names.len()]);
+        self.names.len() - 1
+    }
+
+    pub fn name(&self, i: usize) -> &str {
+        &self.names[i]
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn get(&self, from: usize, to: usize) -> Option<W> {
+        self.weights[from][to]
+    }
+
+    /// Set the distance from one node to another, in that direction only.
+    pub fn set(&mut self, from: usize, to: usize, weight: W) {
+        self.weights[from][to] = Some(weight);
+    }
+
+    /// Set the distance between two nodes in both directions.
+    pub fn set_both(&mut self, a: usize, b: usize, weight: W) {
+        self.set(a, b, weight);
+        self.set(b, a, weight);
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Objective {
+    Min,
+    Max,
+}
+
+impl Objective {
+    fn better<W: Ord>(self, a: W, b: W) -> bool {
+        match self {
+            Objective::Min => a < b,
+            Objective::Max => a > b,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Route {
+    /// A path that starts and ends anywhere.
+    Path,
+    /// A cycle that returns to where it started.
+    Cycle,
+}
+
+/// A route that visits every node once.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Tour<W> {
+    /// The nodes in the order they are visited. A cycle starts at node 0
+    /// and does not repeat it at the end.
+    pub order: Vec<usize>,
+    pub cost: W,
+}
+
+/// The best route that visits every node once, or `None` if there is no
+/// route at all.
+pub fn solve<W>(dist: &Distances<W>, objective: Objective, route: Route) -> Option<Tour<W>>
+where
+    W: Copy + Ord + Default + Add<Output = W>,
+{
+    let n = dist.len();
+    if n == 0 {
+        return None;
+    }
+    assert!(n < usize::BITS as usize, "too many nodes: {}", n);
+
+    // best[mask * n + j]: the best route through the nodes in `mask` that
+    // ends at `j`. Cycles all start at node 0.
+    let full = (1 << n) - 1;
+    let at = |mask: usize, j: usize| mask * n + j;
+    let mut best: Vec<Option<W>> = vec![None; (full + 1) * n];
+    match route {
+        Route::Path => (0..n).for_each(|i| best[at(1 << i, i)] = Some(W::default())),
+        Route::Cycle => best[at(1, 0)] = Some(W::default()),
+    }
+
+    for mask in 1..=full {
+        for j in 0..n {
+            let Some(cost) = best[at(mask, j)] else { continue };
+            for k in (0..n).filter(|k| mask & 1 << k == 0) {
+                let Some(weight) = dist.get(j, k) else { continue };
+                let next = &mut best[at(mask | 1 << k, k)];
+                if next.is_none_or(|next| objective.better(cost + weight, next)) {
+                    *next = Some(cost + weight);
+                }
+            }
+        }
+    }
+
+    let finish = |j: usize| {
+        let cost = best[at(full, j)]?;
+        match route {
+            Route::Cycle if n > 1 => Some(cost + dist.get(j, 0)?),
+            _ => Some(cost),
+        }
+    };
+    let (mut end, cost) = (0..n)
+        .filter_map(|j| Some((j, finish(j)?)))
+        .reduce(|a, b| if objective.better(b.1, a.1) { b } else { a })?;
+
+    // Walk back through the table to the start
+    let mut order = vec![end];
+    let mut mask = full;
+    while mask.count_ones() > 1 {
+        let cost = best[at(mask, end)].unwrap();
+        mask &= !(1 << end);
+        end = (0..n)
+            .filter(|&prev| mask & 1 << prev != 0)
+            .find(|&prev| match (best[at(mask, prev)], dist.get(prev, end)) {
+                (Some(before), Some(weight)) => before + weight == cost,
+                _ => false,
+            })
+            .unwrap();
+        order.push(end);
+    }
+    order.reverse();
+
+    Some(Tour { order, cost })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Cities on a line at these positions, all connected.
+    fn line(positions: &[i64]) -> Distances<i64> {
+        let mut dist = Distances::new();
+        for (i, a) in positions.iter().enumerate() {
+            let a_node = dist.node(&format!("city{}", i));
+            for (j, b) in positions.iter().enumerate().take(i) {
+                let b_node = dist.node(&format!("city{}", j));
+                dist.set_both(a_node, b_node, (a - b).abs());
+            }
+        }
+        dist
+    }
+
+    fn cost(dist: &Distances<i64>, tour: &Tour<i64>, route: Route) -> i64 {
+        let mut order = tour.order.clone();
+        if route == Route::Cycle {
+            order.push(order[0]);
+        }
+        order.windows(2).map(|w| dist.get(w[0], w[1]).unwrap()).sum()
+    }
+
+    #[test]
+    fn test_path() {
+        let dist = line(&[5, 0, 9, 2, 7]);
+        let min = solve(&dist, Objective::Min, Route::Path).unwrap();
+        assert_eq!(min.cost, 9);
+        assert!(min.order == [1, 3, 0, 4, 2] || min.order == [2, 4, 0, 3, 1]);
+
+        let max = solve(&dist, Objective::Max, Route::Path).unwrap();
+        assert_eq!(max.cost, cost(&dist, &max, Route::Path));
+        assert_eq!(max.cost, 26);
+    }
+
+    #[test]
+    fn test_cycle() {
+        let dist = line(&[5, 0, 9, 2, 7]);
+        let min = solve(&dist, Objective::Min, Route::Cycle).unwrap();
+        assert_eq!(min.cost, 18);
+        assert_eq!(min.order[0], 0);
+        assert_eq!(min.cost, cost(&dist, &min, Route::Cycle));
+
+        let max = solve(&dist, Objective::Max, Route::Cycle).unwrap();
+        assert_eq!(max.cost, 28);
+        assert_eq!(max.cost, cost(&dist, &max, Route::Cycle));
+    }
+
+    #[test]
+    fn test_missing_edges() {
+        // One-way streets from a to b and from b to c
+        let mut dist = Distances::new();
+        let (a, b, c) = (dist.node("a"), dist.node("b"), dist.node("c"));
+        dist.set(a, b, 1);
+        dist.set(b, c, 1);
+        let path = solve(&dist, Objective::Min, Route::Path).unwrap();
+        assert_eq!((path.order, path.cost), (vec![a, b, c], 2));
+        assert_eq!(solve(&dist, Objective::Min, Route::Cycle), None);
+
+        dist.set(c, a, 5);
+        assert_eq!(solve(&dist, Objective::Max, Route::Cycle).unwrap().cost, 7);
+        assert_eq!(solve(&Distances::<i64>::new(), Objective::Min, Route::Path), None);
+    }
+
+    #[test]
+    fn test_many_nodes() {
+        let positions: Vec<i64> = (0..18).map(|i| (i * 7919) % 101).collect();
+        let dist = line(&positions);
+        let min = solve(&dist, Objective::Min, Route::Path).unwrap();
+        let spread = positions.iter().max().unwrap() - positions.iter().min().unwrap();
+        assert_eq!(min.cost, spread);
+        assert_eq!(solve(&dist, Objective::Min, Route::Cycle).unwrap().cost, 2 * spread);
+    }
+}